@@ -0,0 +1,69 @@
+/// A JSON node whose strings, properties and elements all live in an `Arena`.
+/// 
+/// Nodes are cheap to copy since they only hold references into the arena.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArenaJsonNode<'a> {
+    Object(&'a [(&'a str, ArenaJsonNode<'a>)]),
+    Array(&'a [ArenaJsonNode<'a>]),
+    String(&'a str),
+    Integer(i64),
+
+    /// An integer too large for `i64` which still fits in a `u64`.
+    UInteger(u64),
+    Float(f64),
+    Boolean(bool),
+    Null,
+}
+
+impl<'a> ArenaJsonNode<'a> {
+    /// Gets the value of the first property named `property_name` if the node is an object.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the property to get.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{Arena, ArenaJsonNode};
+    /// 
+    /// let arena = Arena::new();
+    /// let node = arena.parse(r#"{"name": "Jason"}"#).unwrap();
+    /// 
+    /// assert_eq!(node.get("name"), Some(&ArenaJsonNode::String("Jason")));
+    /// assert_eq!(node.get("age"), None);
+    /// ```
+    pub fn get(&self, property_name: &str) -> Option<&'a ArenaJsonNode<'a>> {
+        match self {
+            ArenaJsonNode::Object(properties) => properties
+                .iter()
+                .find(|(key, _)| *key == property_name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Gets the element at `index` if the node is an array.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `index` - Index of the element to get.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{Arena, ArenaJsonNode};
+    /// 
+    /// let arena = Arena::new();
+    /// let node = arena.parse("[1, 2]").unwrap();
+    /// 
+    /// assert_eq!(node.index(1), Some(&ArenaJsonNode::Integer(2)));
+    /// assert_eq!(node.index(2), None);
+    /// ```
+    pub fn index(&self, index: usize) -> Option<&'a ArenaJsonNode<'a>> {
+        match self {
+            ArenaJsonNode::Array(elements) => elements.get(index),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use bumpalo::Bump;
+
+use crate::arena::ArenaJsonNode;
+use crate::parsing::{scan, tokens, JsonNodeParser, ParseOptions, RawToken, TokenKind, Tokenizer};
+use crate::Result;
+
+/// Holds the nodes of many parsed documents in one growing allocation, so parsing lots of small documents doesn't allocate every node separately.
+/// 
+/// Everything parsed into the arena lives until the arena is dropped.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{Arena, ArenaJsonNode};
+/// 
+/// let arena = Arena::new();
+/// 
+/// let documents = [r#"{"id": 1}"#, r#"{"id": 2}"#]
+///     .into_iter()
+///     .map(|json| arena.parse(json))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// 
+/// assert_eq!(documents[1].get("id"), Some(&ArenaJsonNode::Integer(2)));
+/// ```
+#[derive(Debug, Default)]
+pub struct Arena {
+    bump: Bump,
+}
+
+impl Arena {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        Arena { bump: Bump::new() }
+    }
+
+    /// Parse a JSON string slice into nodes allocated in the arena.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
+    /// 
+    /// # Remarks
+    /// 
+    /// Accepts the same JSON as `JsonNode::parse`, including skipping a UTF-8 byte order mark at the very start.
+    /// The JSON is copied into the arena, so `json` doesn't need to outlive the returned node.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the error `JsonNode::parse` would return for `json`.
+    /// Strings read before the error stay allocated until the arena is dropped.
+    pub fn parse<'a>(&'a self, json: &str) -> Result<&'a ArenaJsonNode<'a>> {
+        let json = JsonNodeParser::strip_byte_order_mark(json);
+        let mut builder = ArenaTreeBuilder { arena: self, open_containers: Vec::new(), key: None, root: None };
+
+        scan(Tokenizer::with_options(json, &ParseOptions::default()), |token| builder.push(token))?;
+
+        let root = builder.root.expect("a successful scan always contains a value");
+
+        Ok(self.bump.alloc(root))
+    }
+
+    /// The number of bytes the arena has allocated for nodes so far.
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump.allocated_bytes()
+    }
+}
+
+/// An object or array which has been opened but not yet closed.
+enum OpenContainer<'a> {
+    Object(Vec<(&'a str, ArenaJsonNode<'a>)>),
+    Array(Vec<ArenaJsonNode<'a>>),
+}
+
+/// Builds a tree in the arena from the tokens of a document which the scanner has already checked.
+struct ArenaTreeBuilder<'a> {
+    arena: &'a Arena,
+    // Every open container together with the key it will be inserted under in its parent object.
+    open_containers: Vec<(Option<&'a str>, OpenContainer<'a>)>,
+    key: Option<&'a str>,
+    root: Option<ArenaJsonNode<'a>>,
+}
+
+impl<'a> ArenaTreeBuilder<'a> {
+    fn push(&mut self, token: &RawToken) {
+        let bump = &self.arena.bump;
+
+        let is_key = matches!(self.open_containers.last(), Some((_, OpenContainer::Object(_))))
+            && self.key.is_none()
+            && token.kind == TokenKind::String;
+
+        if is_key {
+            self.key = Some(bump.alloc_str(&JsonNodeParser::key_text(token.text)));
+            return;
+        }
+
+        let node = match token.kind {
+            TokenKind::BraceOpen => {
+                self.open_containers.push((self.key.take(), OpenContainer::Object(Vec::new())));
+                return;
+            },
+            TokenKind::BracketOpen => {
+                self.open_containers.push((self.key.take(), OpenContainer::Array(Vec::new())));
+                return;
+            },
+            TokenKind::BraceClose | TokenKind::BracketClose => {
+                let (key, container) = self.open_containers.pop().expect("the scanner only accepts balanced closing tokens");
+                self.key = key;
+
+                match container {
+                    OpenContainer::Object(properties) => ArenaJsonNode::Object(bump.alloc_slice_fill_iter(properties)),
+                    OpenContainer::Array(elements) => ArenaJsonNode::Array(bump.alloc_slice_fill_iter(elements)),
+                }
+            },
+            TokenKind::Colon | TokenKind::Comma => return,
+            TokenKind::String => ArenaJsonNode::String(bump.alloc_str(&JsonNodeParser::unescape(token.text))),
+            TokenKind::Number => Self::number(token.text),
+            TokenKind::Boolean => ArenaJsonNode::Boolean(token.text.eq_ignore_ascii_case(tokens::TRUE)),
+            TokenKind::Null => ArenaJsonNode::Null,
+        };
+
+        match self.open_containers.last_mut() {
+            Some((_, OpenContainer::Object(properties))) => {
+                properties.push((self.key.take().expect("the scanner only accepts values after a key"), node));
+            },
+            Some((_, OpenContainer::Array(elements))) => elements.push(node),
+            None => self.root = Some(node),
+        }
+    }
+
+    fn number(text: &str) -> ArenaJsonNode<'a> {
+        if let Ok(integer) = text.parse::<i64>() {
+            return ArenaJsonNode::Integer(integer);
+        }
+
+        if let Ok(integer) = text.parse::<u64>() {
+            return ArenaJsonNode::UInteger(integer);
+        }
+
+        ArenaJsonNode::Float(text.parse().expect("the tokenizer only accepts numbers which parse as a float"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arena;
+    use crate::{ArenaJsonNode, JsonNodeError};
+
+    #[test]
+    fn parse_many_documents_into_one_arena() {
+        let arena = Arena::new();
+
+        let documents = (0..100)
+            .map(|id| format!(r#"{{"id": {}, "tags": ["a", "b"], "score": 1.5, "active": true, "parent": null}}"#, id))
+            .map(|json| arena.parse(&json).unwrap())
+            .collect::<Vec<_>>();
+
+        for (id, document) in documents.iter().enumerate() {
+            assert_eq!(document.get("id"), Some(&ArenaJsonNode::Integer(id as i64)));
+            assert_eq!(document.get("tags").and_then(|tags| tags.index(1)), Some(&ArenaJsonNode::String("b")));
+            assert_eq!(document.get("score"), Some(&ArenaJsonNode::Float(1.5)));
+            assert_eq!(document.get("active"), Some(&ArenaJsonNode::Boolean(true)));
+            assert_eq!(document.get("parent"), Some(&ArenaJsonNode::Null));
+        }
+
+        assert!(arena.allocated_bytes() > 0);
+    }
+
+    #[test]
+    fn parse_errors_match_json_node() {
+        let arena = Arena::new();
+
+        assert_eq!(arena.parse("[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+        assert_eq!(arena.parse(""), Err(JsonNodeError::EmptyJson(None)));
+        assert_eq!(arena.parse("\u{FEFF}[]"), Ok(&ArenaJsonNode::Array(&[])));
+    }
+}
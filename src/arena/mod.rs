@@ -0,0 +1,5 @@
+pub(crate) mod json_arena;
+pub(crate) mod arena_json_node;
+
+pub use json_arena::*;
+pub use arena_json_node::*;
\ No newline at end of file
@@ -1,4 +1,7 @@
 use std::{error::Error, fmt::Display};
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::Utf8Error;
 
 pub type Result<T> = std::result::Result<T, JsonNodeError>;
 
@@ -20,23 +23,214 @@ pub enum JsonNodeError {
     /// The JSON object does not have a property with the given key.
     /// The `String` is the key that was not found.
     KeyNotFound(String),
+
+    /// A path doesn't lead to any node.
+    /// The `String` is the path which was looked up.
+    PathNotFound(String),
+
+    /// Two paths disagree about the shape of the tree, like `a` being both a value and an object with the property `b`.
+    /// The `String` is the path which conflicted with an earlier one.
+    ConflictingPath(String),
+
+    /// An object key is not a properly quoted string.
+    /// The `String` is the key as it appeared in the JSON.
+    InvalidKey(String),
+
+    /// The JSON contains a character which can't start a token, or which isn't allowed where it is.
+    UnexpectedCharacter {
+        position: usize,
+        character: char,
+    },
+
+    /// The JSON contains a token which is malformed or isn't allowed where it is.
+    /// `token` is the text of the token as it appeared in the JSON.
+    UnexpectedToken {
+        position: usize,
+        token: String,
+    },
+
+    /// The JSON ends in the middle of a value, like an array without its closing bracket.
+    /// `position` is the length of the JSON.
+    UnexpectedEndOfInput {
+        position: usize,
+    },
+
+    /// The JSON ends inside a string, because its closing quote is missing or escaped like in `"abc\"`.
+    /// `position` is where the opening quote of the string is.
+    UnterminatedString {
+        position: usize,
+    },
+
+    /// The JSON node is a different kind of node than the operation requires.
+    /// `expected` and `found` are names as returned by `JsonNode::type_name`.
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    /// A float is `NaN` or infinite, which JSON can't represent.
+    /// The `String` is the float as Rust formats it, like `NaN` or `-inf`.
+    NonFiniteFloat(String),
+
+    /// A number couldn't be parsed.
+    /// The `String` is the reason given by the standard library, like `invalid digit found in string`.
+    InvalidNumber(String),
+
+    /// A string isn't a valid RFC 3339 date or timestamp.
+    /// The `String` is the reason given by the date library.
+    InvalidDateTime(String),
+
+    /// Bytes which were expected to be UTF-8 aren't.
+    /// `position` is the index of the first byte which isn't part of a valid UTF-8 sequence.
+    InvalidUtf8 {
+        position: usize,
+    },
+
+    /// One of several JSON documents couldn't be parsed.
+    /// `index` is the zero-based position of the document and `error` is why it couldn't be parsed.
+    InDocument {
+        index: usize,
+        error: Box<JsonNodeError>,
+    },
+
+    /// An element of an array couldn't be converted.
+    /// `index` is the position of the element in the array and `error` is why it couldn't be converted.
+    InElement {
+        index: usize,
+        error: Box<JsonNodeError>,
+    },
+
+    /// Reading the JSON failed.
+    /// The `String` is the message of the `std::io::Error`.
+    Io(String),
+
+    /// The JSON node could not be converted to TOML.
+    /// The `String` describes which part of the node has no TOML representation.
+    CouldntConvertToToml(String),
 }
 
 impl Display for JsonNodeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            JsonNodeError::EmptyJson(parent_node) => {
-                if let Some(parent_node) = parent_node {
-                    return write!(f, "{}", parent_node);
-                }
-                
-                write!(f, "{}", "Json node has no parent".to_string())
+            JsonNodeError::EmptyJson(parent_node) => match parent_node {
+                Some(parent_node) => write!(f, "empty JSON in: {}", parent_node),
+                None => write!(f, "empty JSON"),
             },
-            JsonNodeError::CouldntParseNode(node) => write!(f, "{}", node),
-            JsonNodeError::MultiplePropertiesWithSameKey(key) => write!(f, "{}", key),
-            JsonNodeError::KeyNotFound(key) => write!(f, "{}", key),
+            JsonNodeError::CouldntParseNode(node) => write!(f, "could not parse node: {}", node),
+            JsonNodeError::MultiplePropertiesWithSameKey(key) => write!(f, "duplicate key: {}", key),
+            JsonNodeError::KeyNotFound(key) => write!(f, "key not found: {}", key),
+            JsonNodeError::PathNotFound(path) => write!(f, "path not found: {}", path),
+            JsonNodeError::ConflictingPath(path) => write!(f, "conflicting path: {}", path),
+            JsonNodeError::InvalidKey(key) => write!(f, "invalid key: {}", key),
+            JsonNodeError::UnexpectedCharacter { position, character } => write!(f, "unexpected character {:?} at position {}", character, position),
+            JsonNodeError::UnexpectedToken { position, token } => write!(f, "unexpected token `{}` at position {}", token, position),
+            JsonNodeError::UnexpectedEndOfInput { position } => write!(f, "unexpected end of input at position {}", position),
+            JsonNodeError::UnterminatedString { position } => write!(f, "unterminated string starting at position {}", position),
+            JsonNodeError::TypeMismatch { expected, found } => write!(f, "expected {}, found {}", expected, found),
+            JsonNodeError::NonFiniteFloat(value) => write!(f, "non-finite float: {}", value),
+            JsonNodeError::InvalidNumber(reason) => write!(f, "invalid number: {}", reason),
+            JsonNodeError::InvalidDateTime(reason) => write!(f, "invalid date time: {}", reason),
+            JsonNodeError::InvalidUtf8 { position } => write!(f, "invalid UTF-8 at position {}", position),
+            JsonNodeError::InDocument { index, error } => write!(f, "document {}: {}", index, error),
+            JsonNodeError::InElement { index, error } => write!(f, "element {}: {}", index, error),
+            JsonNodeError::Io(message) => write!(f, "I/O error: {}", message),
+            JsonNodeError::CouldntConvertToToml(reason) => write!(f, "could not convert to TOML: {}", reason),
         }
     }
 }
 
-impl Error for JsonNodeError {}
\ No newline at end of file
+impl Error for JsonNodeError {}
+
+impl From<ParseIntError> for JsonNodeError {
+    fn from(error: ParseIntError) -> Self {
+        JsonNodeError::InvalidNumber(error.to_string())
+    }
+}
+
+impl From<ParseFloatError> for JsonNodeError {
+    fn from(error: ParseFloatError) -> Self {
+        JsonNodeError::InvalidNumber(error.to_string())
+    }
+}
+
+impl From<io::Error> for JsonNodeError {
+    fn from(error: io::Error) -> Self {
+        JsonNodeError::Io(error.to_string())
+    }
+}
+
+impl From<Utf8Error> for JsonNodeError {
+    fn from(error: Utf8Error) -> Self {
+        JsonNodeError::InvalidUtf8 { position: error.valid_up_to() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonNodeError;
+    use crate::{JsonNode, JsonPropertyMap};
+
+    #[test]
+    fn type_mismatch_message() {
+        let error = JsonNodeError::TypeMismatch {
+            expected: "object",
+            found: JsonNode::Array(Vec::new()).type_name(),
+        };
+
+        assert_eq!(error.to_string(), "expected object, found array");
+
+        let error = JsonNode::Object(JsonPropertyMap::new()).to_jsonl().unwrap_err();
+        assert_eq!(error.to_string(), "expected array, found object");
+    }
+
+    #[test]
+    fn display_messages() {
+        let messages = [
+            (JsonNodeError::EmptyJson(None), "empty JSON"),
+            (JsonNodeError::EmptyJson(Some(Box::new("[1, ]".to_owned()))), "empty JSON in: [1, ]"),
+            (JsonNodeError::CouldntParseNode("tru".to_owned()), "could not parse node: tru"),
+            (JsonNodeError::MultiplePropertiesWithSameKey("age".to_owned()), "duplicate key: age"),
+            (JsonNodeError::KeyNotFound("age".to_owned()), "key not found: age"),
+            (JsonNodeError::PathNotFound("/children/2".to_owned()), "path not found: /children/2"),
+            (JsonNodeError::ConflictingPath("a.b".to_owned()), "conflicting path: a.b"),
+            (JsonNodeError::InvalidKey("age".to_owned()), "invalid key: age"),
+            (JsonNodeError::UnexpectedCharacter { position: 4, character: '#' }, "unexpected character '#' at position 4"),
+            (JsonNodeError::UnexpectedToken { position: 1, token: "01".to_owned() }, "unexpected token `01` at position 1"),
+            (JsonNodeError::UnexpectedEndOfInput { position: 5 }, "unexpected end of input at position 5"),
+            (JsonNodeError::UnterminatedString { position: 0 }, "unterminated string starting at position 0"),
+            (JsonNodeError::TypeMismatch { expected: "object", found: "array" }, "expected object, found array"),
+            (JsonNodeError::NonFiniteFloat("NaN".to_owned()), "non-finite float: NaN"),
+            (JsonNodeError::InvalidNumber("number too large to fit in target type".to_owned()), "invalid number: number too large to fit in target type"),
+            (JsonNodeError::InvalidDateTime("premature end of input".to_owned()), "invalid date time: premature end of input"),
+            (JsonNodeError::InvalidUtf8 { position: 2 }, "invalid UTF-8 at position 2"),
+            (
+                JsonNodeError::InDocument { index: 1, error: Box::new(JsonNodeError::KeyNotFound("id".to_owned())) },
+                "document 1: key not found: id",
+            ),
+            (
+                JsonNodeError::InElement { index: 2, error: Box::new(JsonNodeError::TypeMismatch { expected: "integer", found: "string" }) },
+                "element 2: expected integer, found string",
+            ),
+            (JsonNodeError::Io("unexpected end of file".to_owned()), "I/O error: unexpected end of file"),
+            (JsonNodeError::CouldntConvertToToml("null at root".to_owned()), "could not convert to TOML: null at root"),
+        ];
+
+        for (error, message) in messages {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn convert_std_errors() {
+        let bytes = vec![b'[', b'1', 0xFF, b']'];
+        let utf8_error = String::from_utf8(bytes).unwrap_err().utf8_error();
+
+        assert_eq!(JsonNodeError::from(utf8_error), JsonNodeError::InvalidUtf8 { position: 2 });
+
+        let parse = |text: &str| -> Result<i64, JsonNodeError> { Ok(text.parse::<i64>()?) };
+
+        assert_eq!(parse("12"), Ok(12));
+        assert_eq!(parse("1x"), Err(JsonNodeError::InvalidNumber("invalid digit found in string".to_owned())));
+        assert_eq!(JsonNodeError::from("x".parse::<f64>().unwrap_err()), JsonNodeError::InvalidNumber("invalid float literal".to_owned()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+
+use crate::errors::JsonNodeError;
+use crate::models::JsonNode;
+use crate::{FromJsonNode, Result, ToJsonNode};
+
+/// Converts the timestamp into an RFC 3339 string in UTC like `2024-02-29T13:45:30Z`, with as many fractional digits as the timestamp needs.
+impl ToJsonNode for DateTime<Utc> {
+    fn to_json_node(&self) -> JsonNode {
+        JsonNode::String(self.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+/// Converts the date into an RFC 3339 full date like `2024-02-29`.
+impl ToJsonNode for NaiveDate {
+    fn to_json_node(&self) -> JsonNode {
+        JsonNode::String(self.format("%Y-%m-%d").to_string())
+    }
+}
+
+/// Parses an RFC 3339 timestamp with any offset, converting it to UTC.
+impl FromJsonNode for DateTime<Utc> {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        let text = string(node)?;
+
+        DateTime::parse_from_rfc3339(text)
+            .map(|date_time| date_time.with_timezone(&Utc))
+            .map_err(|error| JsonNodeError::InvalidDateTime(error.to_string()))
+    }
+}
+
+/// Parses an RFC 3339 full date like `2024-02-29`.
+impl FromJsonNode for NaiveDate {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        let text = string(node)?;
+
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").map_err(|error| JsonNodeError::InvalidDateTime(error.to_string()))
+    }
+}
+
+fn string(node: &JsonNode) -> Result<&str> {
+    node.as_string().ok_or(JsonNodeError::TypeMismatch { expected: "string", found: node.type_name() })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    use crate::{FromJsonNode, JsonNode, JsonNodeError, ToJsonNode};
+
+    #[test]
+    fn chrono_round_trip() {
+        let node = JsonNode::String("2024-02-29T13:45:30Z".to_owned());
+        let timestamp = DateTime::<Utc>::from_json_node(&node).unwrap();
+
+        assert_eq!(timestamp, Utc.with_ymd_and_hms(2024, 2, 29, 13, 45, 30).unwrap());
+        assert_eq!(timestamp.to_json_node(), node);
+
+        let offset = JsonNode::String("2024-02-29T15:45:30.250+02:00".to_owned());
+        assert_eq!(DateTime::<Utc>::from_json_node(&offset).unwrap().to_json_node(), JsonNode::String("2024-02-29T13:45:30.250Z".to_owned()));
+
+        let date = NaiveDate::from_json_node(&JsonNode::String("2024-02-29".to_owned())).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(date.to_json_node().to_json_string(), r#""2024-02-29""#);
+
+        assert!(matches!(NaiveDate::from_json_node(&JsonNode::String("2023-02-29".to_owned())), Err(JsonNodeError::InvalidDateTime(_))));
+        assert_eq!(DateTime::<Utc>::from_json_node(&JsonNode::Integer(0)), Err(JsonNodeError::TypeMismatch { expected: "string", found: "integer" }));
+    }
+}
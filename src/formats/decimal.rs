@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+
+use crate::errors::JsonNodeError;
+use crate::models::JsonNode;
+use crate::{FromJsonNode, Result, ToJsonNode};
+
+impl JsonNode {
+    /// Gets the value of the node as a `Decimal` if it is a number which fits in one exactly.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, ParseOptions};
+    /// use rust_decimal::Decimal;
+    /// 
+    /// let options = ParseOptions {
+    ///     preserve_number_text: true,
+    ///     ..ParseOptions::default()
+    /// };
+    /// 
+    /// let node = JsonNode::parse_with_options("[0.1, 0.2]", &options).unwrap();
+    /// let array = node.as_array().unwrap();
+    /// let sum = array[0].as_decimal().unwrap() + array[1].as_decimal().unwrap();
+    /// 
+    /// assert_eq!(sum, Decimal::new(3, 1));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Parse with `ParseOptions::preserve_number_text` to get base-10 exact values, since numbers are then kept as the text they were written as.
+    /// Floats are converted through the fewest digits which parse back to the same `f64`, so `JsonNode::Float(0.1)` gives exactly `0.1`.
+    /// Returns `None` for numbers with more significant digits than a `Decimal` holds rather than rounding them.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            JsonNode::Integer(value) => Some(Decimal::from(*value)),
+            JsonNode::UInteger(value) => Some(Decimal::from(*value)),
+            JsonNode::Float(value) if value.is_finite() => Decimal::from_str_exact(&value.to_string()).ok(),
+            JsonNode::RawNumber(text) if text.contains(['e', 'E']) => Decimal::from_scientific(text).ok(),
+            JsonNode::RawNumber(text) => Decimal::from_str_exact(text).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Converts the decimal into a `JsonNode::RawNumber`, so it is serialized with its exact digits.
+impl ToJsonNode for Decimal {
+    fn to_json_node(&self) -> JsonNode {
+        JsonNode::RawNumber(self.to_string())
+    }
+}
+
+impl FromJsonNode for Decimal {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::Integer(_) | JsonNode::UInteger(_) | JsonNode::Float(_) | JsonNode::RawNumber(_) => node
+                .as_decimal()
+                .ok_or_else(|| JsonNodeError::InvalidNumber(format!("{} doesn't fit in a decimal", node))),
+            _ => Err(JsonNodeError::TypeMismatch { expected: "number", found: node.type_name() }),
+        }
+    }
+}
+
+impl TryFrom<&JsonNode> for Decimal {
+    type Error = JsonNodeError;
+
+    fn try_from(node: &JsonNode) -> Result<Self> {
+        Decimal::from_json_node(node)
+    }
+}
+
+impl TryFrom<JsonNode> for Decimal {
+    type Error = JsonNodeError;
+
+    fn try_from(node: JsonNode) -> Result<Self> {
+        Decimal::from_json_node(&node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::ParseOptions;
+
+    fn parse_exact(json: &str) -> JsonNode {
+        let options = ParseOptions {
+            preserve_number_text: true,
+            ..ParseOptions::default()
+        };
+
+        JsonNode::parse_with_options(json, &options).unwrap()
+    }
+
+    #[test]
+    fn decimals_round_trip_exactly() {
+        let node = parse_exact(r#"{"price": 19.99, "discount": 0.1, "quantity": 3}"#);
+        let object = node.as_object().unwrap();
+
+        let price = object.get("price").unwrap().as_decimal().unwrap();
+        let discount = object.get("discount").unwrap().as_decimal().unwrap();
+        let quantity = object.get("quantity").unwrap().as_decimal().unwrap();
+
+        assert_eq!(price, Decimal::from_str("19.99").unwrap());
+        assert_eq!(discount, Decimal::from_str("0.1").unwrap());
+        assert_eq!(price * quantity, Decimal::from_str("59.97").unwrap());
+        assert_eq!(node.to_json_string(), r#"{"price":19.99,"discount":0.1,"quantity":3}"#);
+
+        let total = JsonNode::Array(vec![(discount + discount + discount).to_json_node(), price.to_json_node()]);
+        assert_eq!(total.to_json_string(), "[0.3,19.99]");
+    }
+
+    #[test]
+    fn decimal_conversions() {
+        assert_eq!(JsonNode::Float(19.99).as_decimal(), Some(Decimal::from_str("19.99").unwrap()));
+        assert_eq!(parse_exact("1.5e3").as_decimal(), Some(Decimal::from(1500)));
+        assert_eq!(parse_exact("0.1234567890123456789012345678901").as_decimal(), None);
+        assert_eq!(JsonNode::Float(f64::NAN).as_decimal(), None);
+        assert_eq!(Decimal::try_from(&JsonNode::UInteger(u64::MAX)), Ok(Decimal::from(u64::MAX)));
+        assert_eq!(Decimal::try_from(&JsonNode::Null), Err(JsonNodeError::TypeMismatch { expected: "number", found: "null" }));
+    }
+}
@@ -0,0 +1,88 @@
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+
+use crate::models::JsonNode;
+use crate::Result;
+
+impl JsonNode {
+    /// Decompresses gzip-compressed JSON from a reader and parses it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `reader` - The gzip-compressed JSON, like an opened `.json.gz` file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::Write;
+    /// 
+    /// use flate2::{write::GzEncoder, Compression};
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    /// encoder.write_all(br#"{"name": "Jason"}"#).unwrap();
+    /// let compressed = encoder.finish().unwrap();
+    /// 
+    /// let node = JsonNode::from_gzip_reader(compressed.as_slice()).unwrap();
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"name":"Jason"}"#);
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::Io` if reading or decompressing fails, `JsonNodeError::InvalidUtf8` if the decompressed bytes aren't UTF-8,
+    /// and any error `JsonNode::parse` returns for the decompressed JSON.
+    pub fn from_gzip_reader<R: Read>(reader: R) -> Result<JsonNode> {
+        let mut json = Vec::new();
+        GzDecoder::new(reader).read_to_end(&mut json)?;
+
+        JsonNode::from_slice(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use crate::{JsonNode, JsonNodeError};
+
+    const SAMPLE_JSON: &str = r#"
+    {
+        "name": "Jason",
+        "age": 30,
+        "isMale": true,
+        "height": 1.8,
+        "numbers": [1, 2, 3, 4, 5],
+        "children": [
+            {
+                "name": "Jason Jr.",
+                "age": 5,
+                "isMale": true,
+                "height": 1.2
+            }
+        ]
+    }"#;
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn from_gzip_reader_sample() {
+        let compressed = gzip(SAMPLE_JSON.as_bytes());
+
+        assert_eq!(JsonNode::from_gzip_reader(compressed.as_slice()).unwrap(), JsonNode::parse(SAMPLE_JSON).unwrap());
+    }
+
+    #[test]
+    fn from_gzip_reader_errors() {
+        assert!(matches!(JsonNode::from_gzip_reader(SAMPLE_JSON.as_bytes()), Err(JsonNodeError::Io(_))));
+        assert_eq!(JsonNode::from_gzip_reader(gzip(b"[1, \xFF]").as_slice()), Err(JsonNodeError::InvalidUtf8 { position: 4 }));
+        assert_eq!(JsonNode::from_gzip_reader(gzip(b"[1, 2").as_slice()), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::errors::JsonNodeError;
+use crate::models::JsonNode;
+use crate::utils::SurroundWith;
+use crate::Result;
+
+impl JsonNode {
+    /// Converts the node tree to canonical JSON as defined by RFC 8785, the JSON Canonicalization Scheme (JCS).
+    /// 
+    /// Two trees with the same content always give byte-for-byte identical JCS, which makes it suitable for hashing and signing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{ "b": 1E3, "a": [true, 0.50] }"#).unwrap();
+    /// 
+    /// assert_eq!(node.to_jcs().unwrap(), r#"{"a":[true,0.5],"b":1000}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// * Object properties are sorted by the UTF-16 code units of their names.
+    /// * Strings only escape `"`, `\` and control characters.
+    /// * Every number is written like JavaScript writes a `Number`, so integers beyond 2^53 lose precision just as they would in JavaScript.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::NonFiniteFloat` if the tree contains a `NaN` or infinite float, since those have no JSON representation.
+    pub fn to_jcs(&self) -> Result<String> {
+        let mut jcs = String::new();
+        write_jcs(&mut jcs, self)?;
+        Ok(jcs)
+    }
+}
+
+fn write_jcs(jcs: &mut String, node: &JsonNode) -> Result<()> {
+    match node {
+        JsonNode::Object(object) => {
+            let mut properties = object
+                .iter()
+                .filter(|(_, value)| !value.is_omitted_from_objects())
+                .collect::<Vec<_>>();
+
+            properties.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+            jcs.push('{');
+
+            for (index, (key, value)) in properties.into_iter().enumerate() {
+                if index > 0 {
+                    jcs.push(',');
+                }
+
+                jcs.push_str(&escape_string(key));
+                jcs.push(':');
+                write_jcs(jcs, value)?;
+            }
+
+            jcs.push('}');
+        },
+        JsonNode::Array(array) => {
+            jcs.push('[');
+
+            for (index, element) in array.iter().enumerate() {
+                if index > 0 {
+                    jcs.push(',');
+                }
+
+                write_jcs(jcs, element)?;
+            }
+
+            jcs.push(']');
+        },
+        JsonNode::String(value) => jcs.push_str(&escape_string(value)),
+        JsonNode::Integer(value) => jcs.push_str(&format_number(*value as f64)?),
+        JsonNode::UInteger(value) => jcs.push_str(&format_number(*value as f64)?),
+        JsonNode::Float(value) => jcs.push_str(&format_number(*value)?),
+        JsonNode::RawNumber(text) => jcs.push_str(&format_number(text.parse().unwrap_or(f64::NAN))?),
+        JsonNode::Boolean(value) => jcs.push_str(&value.to_string()),
+        JsonNode::Null => jcs.push_str("null"),
+        #[cfg(feature = "undefined")]
+        JsonNode::Undefined => jcs.push_str("null"),
+    }
+
+    Ok(())
+}
+
+fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\u{C}' => escaped.push_str("\\f"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{0}'..='\u{1F}' => escaped.push_str(&format!("\\u{:04x}", character as u32)),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped.surround_with("\"", "\"")
+}
+
+/// Formats a number the way ECMAScript's `Number.prototype.toString` does, as RFC 8785 requires.
+fn format_number(value: f64) -> Result<String> {
+    if !value.is_finite() {
+        return Err(JsonNodeError::NonFiniteFloat(value.to_string()));
+    }
+
+    if value == 0.0 {
+        return Ok("0".to_owned());
+    }
+
+    if value < 0.0 {
+        return format_number(-value).map(|formatted| format!("-{}", formatted));
+    }
+
+    // `{:e}` gives the shortest digits which round-trip, like `1.2345e2`.
+    let scientific = format!("{:e}", value);
+    let (mantissa, exponent) = scientific.split_once('e').expect("scientific notation always has an exponent");
+    let digits = mantissa.replace('.', "");
+    let digit_count = digits.len() as i32;
+    // The position of the decimal point relative to the start of the digits.
+    let point = exponent.parse::<i32>().expect("the exponent is always an integer") + 1;
+
+    let formatted = if digit_count <= point && point <= 21 {
+        format!("{}{}", digits, "0".repeat((point - digit_count) as usize))
+    } else if 0 < point && point <= 21 {
+        format!("{}.{}", &digits[..point as usize], &digits[point as usize..])
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let fraction = if digit_count > 1 { format!(".{}", &digits[1..]) } else { String::new() };
+        let sign = if point - 1 < 0 { '-' } else { '+' };
+
+        format!("{}{}e{}{}", &digits[..1], fraction, sign, (point - 1).abs())
+    };
+
+    Ok(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_number;
+    use crate::{JsonNode, JsonNodeError, JsonPropertyMap};
+
+    #[test]
+    fn to_jcs_spec_example() {
+        // The example from section 3.2.2 of RFC 8785. The string is built directly since it is the unescaped form that is canonicalized.
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("numbers".to_owned(), JsonNode::parse("[333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001]").unwrap()),
+            ("string".to_owned(), JsonNode::String("\u{20ac}$\u{000F}\u{000a}A'\u{0042}\u{0022}\u{005c}\\\"/".to_owned())),
+            ("literals".to_owned(), JsonNode::parse("[null, true, false]").unwrap()),
+        ]));
+
+        let expected = r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#;
+
+        assert_eq!(node.to_jcs().unwrap(), expected);
+    }
+
+    #[test]
+    fn to_jcs_sorts_by_utf16() {
+        // U+1F600 is encoded as a surrogate pair starting with 0xD83D, which sorts before U+FB01 even though its code point is larger.
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("\u{FB01}".to_owned(), JsonNode::Integer(1)),
+            ("\u{1F600}".to_owned(), JsonNode::Integer(2)),
+            ("a".to_owned(), JsonNode::Integer(3)),
+        ]));
+
+        assert_eq!(node.to_jcs().unwrap(), "{\"a\":3,\"\u{1F600}\":2,\"\u{FB01}\":1}");
+    }
+
+    #[test]
+    fn format_numbers_like_ecmascript() {
+        let cases = [
+            (0.0, "0"),
+            (-0.0, "0"),
+            (1.0, "1"),
+            (-1.5, "-1.5"),
+            (1e21, "1e+21"),
+            (1e20, "100000000000000000000"),
+            (1e-6, "0.000001"),
+            (1e-7, "1e-7"),
+            (123.456e-10, "1.23456e-8"),
+            (9007199254740992.0, "9007199254740992"),
+            (5e-324, "5e-324"),
+            (f64::MAX, "1.7976931348623157e+308"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(format_number(value).unwrap(), expected);
+        }
+
+        assert_eq!(JsonNode::Float(f64::NAN).to_jcs(), Err(JsonNodeError::NonFiniteFloat("NaN".to_owned())));
+    }
+}
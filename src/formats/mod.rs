@@ -0,0 +1,19 @@
+mod jcs;
+
+#[cfg(feature = "gzip")]
+mod gzip;
+
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+
+#[cfg(feature = "chrono")]
+mod chrono_dates;
+
+#[cfg(feature = "time")]
+mod time_dates;
+
+#[cfg(feature = "toml")]
+mod toml;
+
+#[cfg(feature = "xml")]
+mod xml;
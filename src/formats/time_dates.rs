@@ -0,0 +1,44 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::errors::JsonNodeError;
+use crate::models::JsonNode;
+use crate::{FromJsonNode, Result, ToJsonNode};
+
+/// Converts the timestamp into an RFC 3339 string keeping its offset, like `2024-02-29T13:45:30+02:00`.
+/// 
+/// Timestamps RFC 3339 can't represent, like those after the year 9999, are written with the `Display` format of `OffsetDateTime` instead.
+impl ToJsonNode for OffsetDateTime {
+    fn to_json_node(&self) -> JsonNode {
+        JsonNode::String(self.format(&Rfc3339).unwrap_or_else(|_| self.to_string()))
+    }
+}
+
+/// Parses an RFC 3339 timestamp, keeping its offset.
+impl FromJsonNode for OffsetDateTime {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        let text = node.as_string().ok_or(JsonNodeError::TypeMismatch { expected: "string", found: node.type_name() })?;
+
+        OffsetDateTime::parse(text, &Rfc3339).map_err(|error| JsonNodeError::InvalidDateTime(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use crate::{FromJsonNode, JsonNode, JsonNodeError, ToJsonNode};
+
+    #[test]
+    fn time_round_trip() {
+        let node = JsonNode::String("2024-02-29T13:45:30.25+02:00".to_owned());
+        let timestamp = OffsetDateTime::from_json_node(&node).unwrap();
+
+        assert_eq!(timestamp.unix_timestamp(), 1_709_207_130);
+        assert_eq!(timestamp.millisecond(), 250);
+        assert_eq!(timestamp.to_json_node(), node);
+        assert_eq!(OffsetDateTime::UNIX_EPOCH.to_json_node().to_json_string(), r#""1970-01-01T00:00:00Z""#);
+
+        assert!(matches!(OffsetDateTime::from_json_node(&JsonNode::String("yesterday".to_owned())), Err(JsonNodeError::InvalidDateTime(_))));
+    }
+}
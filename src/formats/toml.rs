@@ -0,0 +1,237 @@
+use std::mem::discriminant;
+
+use crate::errors::JsonNodeError;
+use crate::models::{JsonNode, JsonPropertyMap};
+use crate::utils::SurroundWith;
+use crate::Result;
+
+impl JsonNode {
+    /// Converts the node tree into a TOML document.
+    /// 
+    /// Nested objects become `[table]` sections and arrays of objects become `[[array-of-tables]]` sections.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let config = JsonNode::parse(r#"{"name": "app", "server": {"port": 8080}}"#).unwrap();
+    /// 
+    /// assert_eq!(config.to_toml().unwrap(), "name = \"app\"\n\n[server]\nport = 8080\n");
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::CouldntConvertToToml` if the structure can't be represented in TOML.
+    /// This is the case when the root node isn't an object, when the tree contains a `null` or when an array mixes element types.
+    pub fn to_toml(&self) -> Result<String> {
+        let table = match self {
+            JsonNode::Object(table) => table,
+            _ => return Err(JsonNodeError::CouldntConvertToToml("The root of a TOML document must be an object".to_owned())),
+        };
+
+        let mut toml = String::new();
+        write_table(&mut toml, &mut Vec::new(), table)?;
+
+        Ok(toml)
+    }
+}
+
+fn write_table(toml: &mut String, path: &mut Vec<String>, table: &JsonPropertyMap) -> Result<()> {
+    for (key, value) in table.iter() {
+        if value.is_object() || is_array_of_tables(value) || value.is_omitted_from_objects() {
+            continue;
+        }
+
+        path.push(format_key(key));
+        let value = format_value(value, path)?;
+        path.pop();
+
+        toml.push_str(&format!("{} = {}\n", format_key(key), value));
+    }
+
+    for (key, value) in table.iter() {
+        path.push(format_key(key));
+
+        match value {
+            JsonNode::Object(sub_table) => {
+                write_header(toml, &path.join(".").surround_with("[", "]"));
+                write_table(toml, path, sub_table)?;
+            },
+            JsonNode::Array(elements) if is_array_of_tables(value) => {
+                for element in elements {
+                    write_header(toml, &path.join(".").surround_with("[[", "]]"));
+                    write_table(toml, path, element.as_object().unwrap())?;
+                }
+            },
+            _ => (),
+        }
+
+        path.pop();
+    }
+
+    Ok(())
+}
+
+fn write_header(toml: &mut String, header: &str) {
+    if !toml.is_empty() {
+        toml.push('\n');
+    }
+
+    toml.push_str(header);
+    toml.push('\n');
+}
+
+fn is_array_of_tables(node: &JsonNode) -> bool {
+    match node {
+        JsonNode::Array(elements) => !elements.is_empty() && elements.iter().all(|element| element.is_object()),
+        _ => false,
+    }
+}
+
+fn format_value(node: &JsonNode, path: &[String]) -> Result<String> {
+    match node {
+        JsonNode::String(value) => Ok(format_string(value)),
+        JsonNode::Integer(value) => Ok(value.to_string()),
+        JsonNode::UInteger(_) => Err(JsonNodeError::CouldntConvertToToml(format!("TOML integers must fit in an i64, found a larger one at `{}`", path.join(".")))),
+        JsonNode::Float(value) => Ok(format_float(*value)),
+        JsonNode::Boolean(value) => Ok(value.to_string()),
+        JsonNode::RawNumber(text) => Ok(text.clone()),
+        JsonNode::Null => Err(JsonNodeError::CouldntConvertToToml(format!("TOML has no null value, found one at `{}`", path.join(".")))),
+        #[cfg(feature = "undefined")]
+        JsonNode::Undefined => Err(JsonNodeError::CouldntConvertToToml(format!("TOML has no undefined value, found one at `{}`", path.join(".")))),
+        JsonNode::Array(elements) => {
+            if let Some(first) = elements.first() {
+                if elements.iter().any(|element| discriminant(element) != discriminant(first)) {
+                    return Err(JsonNodeError::CouldntConvertToToml(format!("TOML arrays can't mix types, found one at `{}`", path.join("."))));
+                }
+            }
+
+            Ok(elements
+                .iter()
+                .map(|element| format_value(element, path))
+                .collect::<Result<Vec<String>>>()?
+                .join(", ")
+                .surround_with("[", "]"))
+        },
+        JsonNode::Object(table) => {
+            if table.is_empty() {
+                return Ok("{}".to_owned());
+            }
+
+            Ok(table
+                .iter()
+                .map(|(key, value)| Ok(format!("{} = {}", format_key(key), format_value(value, path)?)))
+                .collect::<Result<Vec<String>>>()?
+                .join(", ")
+                .surround_with("{ ", " }"))
+        },
+    }
+}
+
+fn format_key(key: &str) -> String {
+    let is_bare = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if is_bare {
+        key.to_owned()
+    } else {
+        format_string(key)
+    }
+}
+
+fn format_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        return "nan".to_owned();
+    }
+
+    if value.is_infinite() {
+        return if value > 0.0 { "inf".to_owned() } else { "-inf".to_owned() };
+    }
+
+    // The debug representation always keeps a decimal point or an exponent, which TOML requires for floats.
+    format!("{:?}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JsonNode, JsonNodeError};
+
+    #[test]
+    fn to_toml_converts_config() {
+        let json = r#"
+        {
+            "title": "My App",
+            "port": 8080,
+            "debug": false,
+            "ratio": 0.5,
+            "tags": ["web", "api"],
+            "database": {
+                "host": "localhost",
+                "ports": [5432, 5433]
+            },
+            "users": [
+                { "name": "admin" },
+                { "name": "guest" }
+            ]
+        }"#;
+
+        let toml = JsonNode::parse(json).unwrap().to_toml().unwrap();
+
+        let expected = r#"title = "My App"
+port = 8080
+debug = false
+ratio = 0.5
+tags = ["web", "api"]
+
+[database]
+host = "localhost"
+ports = [5432, 5433]
+
+[[users]]
+name = "admin"
+
+[[users]]
+name = "guest"
+"#;
+
+        assert_eq!(toml, expected);
+    }
+
+    #[test]
+    fn to_toml_fails_on_null() {
+        let json = r#"{ "database": { "password": null } }"#;
+
+        let result = JsonNode::parse(json).unwrap().to_toml();
+
+        assert_eq!(result, Err(JsonNodeError::CouldntConvertToToml("TOML has no null value, found one at `database.password`".to_owned())));
+    }
+
+    #[test]
+    fn to_toml_fails_on_mixed_array() {
+        let json = r#"{ "values": [1, "two"] }"#;
+
+        let result = JsonNode::parse(json).unwrap().to_toml();
+
+        assert!(matches!(result, Err(JsonNodeError::CouldntConvertToToml(_))));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::models::JsonNode;
+
+impl JsonNode {
+    /// Converts the node tree into an XML string with `root` as the name of the outermost element.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `root` - The name of the element wrapping the whole tree.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"name": "Jason", "numbers": [1, 2], "spouse": null}"#).unwrap();
+    /// 
+    /// assert_eq!(
+    ///     node.to_xml("person"),
+    ///     "<person><name>Jason</name><numbers>1</numbers><numbers>2</numbers><spouse/></person>"
+    /// );
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// The mapping works like this:
+    /// 
+    /// * Objects become elements with one child element per property, named after the property.
+    /// * Arrays inside objects become one repeated element per array element, all named after the property.
+    ///   An empty array therefore produces no elements.
+    /// * Arrays which are the root or are nested directly in another array become a single element with one `item` child per array element.
+    /// * Strings and numbers become the text content of their element. Text is escaped.
+    /// * Booleans become the text content `true` or `false`.
+    /// * `null` becomes an empty self-closing element like `<name/>`.
+    /// 
+    /// Property names are used verbatim as element names, so they should be valid XML names.
+    /// No formatting is done, the XML is returned without any spaces or new-lines.
+    pub fn to_xml(&self, root: &str) -> String {
+        let mut xml = String::new();
+        write_element(&mut xml, root, self);
+        xml
+    }
+}
+
+fn write_element(xml: &mut String, name: &str, node: &JsonNode) {
+    match node {
+        JsonNode::Null => xml.push_str(&format!("<{}/>", name)),
+        #[cfg(feature = "undefined")]
+        JsonNode::Undefined => (),
+        JsonNode::Object(properties) => {
+            xml.push_str(&format!("<{}>", name));
+
+            for (key, value) in properties.iter() {
+                match value {
+                    JsonNode::Array(elements) => elements
+                        .iter()
+                        .for_each(|element| write_element(xml, key, element)),
+                    _ => write_element(xml, key, value),
+                }
+            }
+
+            xml.push_str(&format!("</{}>", name));
+        },
+        JsonNode::Array(elements) => {
+            xml.push_str(&format!("<{}>", name));
+            elements.iter().for_each(|element| write_element(xml, "item", element));
+            xml.push_str(&format!("</{}>", name));
+        },
+        JsonNode::String(value) => write_text_element(xml, name, &escape_text(value)),
+        JsonNode::Integer(value) => write_text_element(xml, name, &value.to_string()),
+        JsonNode::UInteger(value) => write_text_element(xml, name, &value.to_string()),
+        JsonNode::Float(value) => write_text_element(xml, name, &value.to_string()),
+        JsonNode::Boolean(value) => write_text_element(xml, name, &value.to_string()),
+        JsonNode::RawNumber(text) => write_text_element(xml, name, text),
+    }
+}
+
+fn write_text_element(xml: &mut String, name: &str, text: &str) {
+    xml.push_str(&format!("<{}>{}</{}>", name, text, name));
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::JsonNode;
+
+    #[test]
+    fn to_xml_nests_elements() {
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "isMale": true,
+            "spouse": null,
+            "numbers": [1, 2],
+            "address": {
+                "city": "Oslo & Bergen"
+            },
+            "matrix": [[1, 2], [3]]
+        }"#;
+
+        let xml = JsonNode::parse(json).unwrap().to_xml("person");
+
+        let expected = concat!(
+            "<person>",
+            "<name>Jason</name>",
+            "<age>30</age>",
+            "<isMale>true</isMale>",
+            "<spouse/>",
+            "<numbers>1</numbers>",
+            "<numbers>2</numbers>",
+            "<address><city>Oslo &amp; Bergen</city></address>",
+            "<matrix><item>1</item><item>2</item></matrix>",
+            "<matrix><item>3</item></matrix>",
+            "</person>",
+        );
+
+        assert_eq!(xml, expected);
+    }
+
+    #[test]
+    fn to_xml_wraps_root_array_items() {
+        let node = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Boolean(false)]);
+
+        assert_eq!(node.to_xml("values"), "<values><item>1</item><item>false</item></values>");
+    }
+}
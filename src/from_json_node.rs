@@ -0,0 +1,360 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::{JsonNode, JsonNodeError, Result};
+
+/// A trait for converting a `JsonNode` into a type.
+pub trait FromJsonNode: Sized {
+    /// Converts a `JsonNode` into the type.
+    ///
+    /// # Implementing the Trait
+    ///
+    /// ```
+    /// use json_node::{FromJsonNode, JsonNode, JsonNodeError, Result};
+    ///
+    /// // Define some type you want to extract from a `JsonNode`.
+    /// struct Celsius(f64);
+    ///
+    /// // Implement the `FromJsonNode` trait for the type.
+    /// impl FromJsonNode for Celsius {
+    ///     fn from_json_node(node: &JsonNode) -> Result<Self> {
+    ///         f64::from_json_node(node).map(Celsius)
+    ///     }
+    /// }
+    ///
+    /// let temperature = Celsius::from_json_node(&JsonNode::Float(21.5)).unwrap();
+    ///
+    /// assert_eq!(temperature.0, 21.5);
+    /// assert!(Celsius::from_json_node(&JsonNode::Null).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonNodeError::TypeMismatch` if the node can't be converted into the type,
+    /// and `JsonNodeError::InvalidNumber` if it is a number which doesn't fit in the type.
+    fn from_json_node(node: &JsonNode) -> Result<Self>;
+}
+
+fn type_mismatch<T>(expected: &'static str, node: &JsonNode) -> Result<T> {
+    Err(JsonNodeError::TypeMismatch {
+        expected,
+        found: node.type_name(),
+    })
+}
+
+/// Parses the text of a `JsonNode::RawNumber` as an integer, naming the target like `an i64` when it doesn't fit.
+fn raw_integer<T: FromStr>(text: &str, target: &str, node: &JsonNode) -> Result<T> {
+    if text.contains(['.', 'e', 'E']) {
+        return type_mismatch("integer", node);
+    }
+
+    text.parse().map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in {}", text, target)))
+}
+
+impl FromJsonNode for JsonNode {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        Ok(node.clone())
+    }
+}
+
+impl FromJsonNode for String {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::String(value) => Ok(value.clone()),
+            _ => type_mismatch("string", node),
+        }
+    }
+}
+
+impl FromJsonNode for i64 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::Integer(value) => Ok(*value),
+            JsonNode::UInteger(value) => i64::try_from(*value).map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in an i64", value))),
+            JsonNode::RawNumber(text) => raw_integer(text, "an i64", node),
+            _ => type_mismatch("integer", node),
+        }
+    }
+}
+
+impl FromJsonNode for u64 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::Integer(value) => u64::try_from(*value).map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in a u64", value))),
+            JsonNode::UInteger(value) => Ok(*value),
+            JsonNode::RawNumber(text) => raw_integer(text, "a u64", node),
+            _ => type_mismatch("integer", node),
+        }
+    }
+}
+
+impl FromJsonNode for f64 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node.as_f64() {
+            Some(value) => Ok(value),
+            None => type_mismatch("number", node),
+        }
+    }
+}
+
+impl FromJsonNode for bool {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::Boolean(value) => Ok(*value),
+            _ => type_mismatch("boolean", node),
+        }
+    }
+}
+
+impl FromJsonNode for i32 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        let value = i64::from_json_node(node)?;
+        i32::try_from(value).map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in an i32", value)))
+    }
+}
+
+impl FromJsonNode for u32 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        // Negative integers are checked here too, so the error names `u32` rather than `u64`.
+        if let JsonNode::Integer(value) = node {
+            return u32::try_from(*value).map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in a u32", value)));
+        }
+
+        let value = u64::from_json_node(node)?;
+        u32::try_from(value).map_err(|_| JsonNodeError::InvalidNumber(format!("{} doesn't fit in a u32", value)))
+    }
+}
+
+impl FromJsonNode for f32 {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        f64::from_json_node(node).map(|value| value as f32)
+    }
+}
+
+/// `null` becomes `None` and any other node is converted into `T`.
+impl<T: FromJsonNode> FromJsonNode for Option<T> {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        match node {
+            JsonNode::Null => Ok(None),
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => Ok(None),
+            _ => T::from_json_node(node).map(Some),
+        }
+    }
+}
+
+/// Converts every element of an array, or every property of an object when the elements are `(String, T)` pairs.
+macro_rules! impl_from_json_node_for_collection {
+    ($($collection:ident<T $(: $($bound:path),+)?>),*) => {
+        $(
+            impl<T: FromJsonNode $($(+ $bound)+)?> FromJsonNode for $collection<T> {
+                fn from_json_node(node: &JsonNode) -> Result<Self> {
+                    match node {
+                        JsonNode::Array(array) => array.iter().map(T::from_json_node).collect(),
+                        _ => type_mismatch("array", node),
+                    }
+                }
+            }
+
+            impl<T: FromJsonNode $($(+ $bound)+)?> FromJsonNode for $collection<(String, T)> {
+                fn from_json_node(node: &JsonNode) -> Result<Self> {
+                    match node {
+                        JsonNode::Object(object) => object
+                            .iter()
+                            .map(|(key, value)| T::from_json_node(value).map(|value| (key.clone(), value)))
+                            .collect(),
+                        _ => type_mismatch("object", node),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_node_for_collection!(Vec<T>, VecDeque<T>, LinkedList<T>, HashSet<T: Eq, Hash>, BTreeSet<T: Ord>, BinaryHeap<T: Ord>);
+
+impl<V: FromJsonNode> FromJsonNode for HashMap<String, V> {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        Vec::<(String, V)>::from_json_node(node).map(HashMap::from_iter)
+    }
+}
+
+impl<V: FromJsonNode> FromJsonNode for BTreeMap<String, V> {
+    fn from_json_node(node: &JsonNode) -> Result<Self> {
+        Vec::<(String, V)>::from_json_node(node).map(BTreeMap::from_iter)
+    }
+}
+
+/// Implements `TryFrom<&JsonNode>` and `TryFrom<JsonNode>` through `FromJsonNode`, so `let n: i64 = node.try_into()?` works.
+macro_rules! impl_try_from_json_node {
+    ($($target:ty),*) => {
+        $(
+            impl TryFrom<&JsonNode> for $target {
+                type Error = JsonNodeError;
+
+                fn try_from(node: &JsonNode) -> Result<Self> {
+                    <$target>::from_json_node(node)
+                }
+            }
+
+            impl TryFrom<JsonNode> for $target {
+                type Error = JsonNodeError;
+
+                fn try_from(node: JsonNode) -> Result<Self> {
+                    <$target>::from_json_node(&node)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_json_node!(i64, u64, i32, u32, f64, f32, bool);
+
+impl TryFrom<&JsonNode> for String {
+    type Error = JsonNodeError;
+
+    fn try_from(node: &JsonNode) -> Result<Self> {
+        String::from_json_node(node)
+    }
+}
+
+impl TryFrom<JsonNode> for String {
+    type Error = JsonNodeError;
+
+    fn try_from(mut node: JsonNode) -> Result<Self> {
+        // Moves the string out rather than cloning it.
+        match node {
+            JsonNode::String(ref mut value) => Ok(std::mem::take(value)),
+            _ => type_mismatch("string", &node),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JsonNode, JsonNodeError, Result};
+
+    #[test]
+    fn try_from_scalars() {
+        let name: String = JsonNode::String("Jason".to_owned()).try_into().unwrap();
+        let age: i64 = (&JsonNode::Integer(30)).try_into().unwrap();
+        let id: u64 = JsonNode::UInteger(u64::MAX).try_into().unwrap();
+        let height: f64 = JsonNode::Float(1.8).try_into().unwrap();
+        let widened: f64 = JsonNode::Integer(2).try_into().unwrap();
+        let is_male: bool = (&JsonNode::Boolean(true)).try_into().unwrap();
+
+        assert_eq!((name.as_str(), age, id, height, widened, is_male), ("Jason", 30, u64::MAX, 1.8, 2.0, true));
+    }
+
+    #[test]
+    fn try_from_mismatch() {
+        let parse_age = |node: &JsonNode| -> Result<i64> {
+            let age: i64 = node.try_into()?;
+            Ok(age)
+        };
+
+        assert_eq!(parse_age(&JsonNode::Float(1.5)), Err(JsonNodeError::TypeMismatch { expected: "integer", found: "float" }));
+        assert_eq!(String::try_from(JsonNode::Null), Err(JsonNodeError::TypeMismatch { expected: "string", found: "null" }));
+        assert_eq!(bool::try_from(JsonNode::Array(Vec::new())), Err(JsonNodeError::TypeMismatch { expected: "boolean", found: "array" }));
+        assert_eq!(f64::try_from(&JsonNode::String("1".to_owned())), Err(JsonNodeError::TypeMismatch { expected: "number", found: "string" }));
+    }
+
+    #[test]
+    fn from_json_node_for_person() {
+        use std::collections::HashMap;
+
+        use crate::FromJsonNode;
+
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            name: String,
+            age: u32,
+            height: f32,
+            nickname: Option<String>,
+            numbers: Vec<i32>,
+            children: Vec<Person>,
+        }
+
+        impl FromJsonNode for Person {
+            fn from_json_node(node: &JsonNode) -> Result<Self> {
+                let null = JsonNode::Null;
+                let property = |name: &str| node.get_path(name).unwrap_or(&null);
+
+                Ok(Person {
+                    name: String::from_json_node(property("name"))?,
+                    age: u32::from_json_node(property("age"))?,
+                    height: f32::from_json_node(property("height"))?,
+                    nickname: Option::from_json_node(property("nickname"))?,
+                    numbers: Option::<Vec<i32>>::from_json_node(property("numbers"))?.unwrap_or_default(),
+                    children: Option::<Vec<Person>>::from_json_node(property("children"))?.unwrap_or_default(),
+                })
+            }
+        }
+
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "height": 1.8,
+            "nickname": "Jay",
+            "numbers": [1, 2, 3],
+            "children": [
+                {"name": "Jasmine", "age": 3, "height": 1.1, "nickname": null}
+            ]
+        }"#;
+
+        let person = Person::from_json_node(&JsonNode::parse(json).unwrap()).unwrap();
+
+        assert_eq!(person, Person {
+            name: "Jason".to_owned(),
+            age: 30,
+            height: 1.8,
+            nickname: Some("Jay".to_owned()),
+            numbers: vec![1, 2, 3],
+            children: vec![Person {
+                name: "Jasmine".to_owned(),
+                age: 3,
+                height: 1.1,
+                nickname: None,
+                numbers: Vec::new(),
+                children: Vec::new(),
+            }],
+        });
+
+        let scores = HashMap::<String, i64>::from_json_node(&JsonNode::parse(r#"{"a": 1, "b": 2}"#).unwrap()).unwrap();
+        assert_eq!(scores, HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]));
+
+        let error = Person::from_json_node(&JsonNode::parse(r#"{"name": "Jason", "age": "30", "height": 1.8}"#).unwrap());
+        assert_eq!(error, Err(JsonNodeError::TypeMismatch { expected: "integer", found: "string" }));
+    }
+
+    #[test]
+    fn from_json_node_out_of_range() {
+        use crate::FromJsonNode;
+
+        assert_eq!(i32::from_json_node(&JsonNode::Integer(1 << 40)), Err(JsonNodeError::InvalidNumber("1099511627776 doesn't fit in an i32".to_owned())));
+        assert_eq!(u32::try_from(JsonNode::Integer(7)), Ok(7));
+        assert_eq!(u64::from_json_node(&JsonNode::Integer(-1)), Err(JsonNodeError::InvalidNumber("-1 doesn't fit in a u64".to_owned())));
+        assert_eq!(u32::from_json_node(&JsonNode::Integer(-1)), Err(JsonNodeError::InvalidNumber("-1 doesn't fit in a u32".to_owned())));
+        assert_eq!(i64::from_json_node(&JsonNode::UInteger(u64::MAX)), Err(JsonNodeError::InvalidNumber("18446744073709551615 doesn't fit in an i64".to_owned())));
+        assert_eq!(i64::from_json_node(&JsonNode::UInteger(7)), Ok(7));
+        assert_eq!(Vec::<bool>::from_json_node(&JsonNode::Null), Err(JsonNodeError::TypeMismatch { expected: "array", found: "null" }));
+        assert_eq!(Vec::<i64>::from_json_node(&JsonNode::parse("[1, true]").unwrap()), Err(JsonNodeError::TypeMismatch { expected: "integer", found: "boolean" }));
+    }
+
+    #[test]
+    fn from_json_node_raw_numbers() {
+        use crate::FromJsonNode;
+
+        let raw = |text: &str| JsonNode::RawNumber(text.to_owned());
+
+        assert_eq!(i64::from_json_node(&raw("-42")), Ok(-42));
+        assert_eq!(u64::from_json_node(&raw("18446744073709551615")), Ok(u64::MAX));
+        assert_eq!(i32::from_json_node(&raw("7")), Ok(7));
+        assert_eq!(f64::from_json_node(&raw("1.10")), Ok(1.1));
+        assert_eq!(i64::from_json_node(&raw("1.10")), Err(JsonNodeError::TypeMismatch { expected: "integer", found: "number" }));
+        assert_eq!(u64::from_json_node(&raw("-1")), Err(JsonNodeError::InvalidNumber("-1 doesn't fit in a u64".to_owned())));
+        assert_eq!(i64::from_json_node(&raw("9223372036854775808")), Err(JsonNodeError::InvalidNumber("9223372036854775808 doesn't fit in an i64".to_owned())));
+    }
+}
@@ -1,9 +1,27 @@
+#[macro_use]
+mod macros;
+
 pub mod models;
 pub mod to_json_node;
+pub mod from_json_node;
+pub mod write_json;
 pub mod errors;
 mod parsing;
+mod serializing;
+mod querying;
+mod formats;
 mod utils;
 
+#[cfg(feature = "arena")]
+mod arena;
+
 pub use models::*;
 pub use to_json_node::*;
+pub use from_json_node::*;
+pub use write_json::*;
 pub use errors::*;
+pub use parsing::{minify, validate, JsonEvent, JsonEventReader, Lexer, LossyNumber, ParseOptions, SpanTable, Token};
+pub use serializing::{reformat, FloatFormat, JsonWriter, LineEnding, SerializeOptions};
+
+#[cfg(feature = "arena")]
+pub use arena::*;
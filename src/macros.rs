@@ -0,0 +1,206 @@
+/// Writes JSON straight into a writer without building a `JsonNode` tree first.
+/// 
+/// The JSON is written compactly, like `JsonNode::to_json_string`.
+/// Object keys are string literals, and any value which isn't `null`, an object or an array is an expression whose type implements `WriteJson`.
+/// Write a type which only implements `ToJsonNode` by interpolating `value.to_json_node()` instead.
+/// 
+/// # Arguments
+/// 
+/// * `writer` - Something implementing `std::io::Write`, like a `Vec<u8>` or a `File`. It is borrowed mutably, not moved.
+/// * The JSON to write, with expressions in place of values.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::json_write;
+/// 
+/// let mut buffer = Vec::new();
+/// let user = "jason";
+/// 
+/// json_write!(buffer, {
+///     "event": "login",
+///     "user": user,
+///     "attempts": 1 + 2,
+///     "tags": ["web", null],
+/// }).unwrap();
+/// 
+/// assert_eq!(String::from_utf8(buffer).unwrap(), r#"{"event":"login","user":"jason","attempts":3,"tags":["web",null]}"#);
+/// ```
+/// 
+/// # Errors
+/// 
+/// Evaluates to an `std::io::Result<()>` containing the first error produced by the writer. Nothing is written after an error,
+/// and the expressions after it aren't evaluated. A `?` or `return` inside an expression applies to the function using the macro.
+#[macro_export]
+macro_rules! json_write {
+    ($writer:expr, $($json:tt)+) => {{
+        let writer: &mut dyn ::std::io::Write = &mut $writer;
+        let mut result: ::std::io::Result<()> = ::std::result::Result::Ok(());
+        $crate::__json_write!(@value writer result ($($json)+));
+        result
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __json_write {
+    // Every write is skipped once one has failed, so `result` holds the first error.
+    (@write $result:ident $write:expr) => {
+        if $result.is_ok() {
+            $result = $write;
+        }
+    };
+
+    (@value $writer:ident $result:ident (null)) => {
+        $crate::__json_write!(@write $result $writer.write_all(b"null"));
+    };
+    (@value $writer:ident $result:ident ([$($elements:tt)*])) => {
+        $crate::__json_write!(@write $result $writer.write_all(b"["));
+        $crate::__json_write!(@array $writer $result [] ($($elements)*));
+        $crate::__json_write!(@write $result $writer.write_all(b"]"));
+    };
+    (@value $writer:ident $result:ident ({$($properties:tt)*})) => {
+        $crate::__json_write!(@write $result $writer.write_all(b"{"));
+        $crate::__json_write!(@object $writer $result [] ($($properties)*));
+        $crate::__json_write!(@write $result $writer.write_all(b"}"));
+    };
+    (@value $writer:ident $result:ident ($($value:tt)+)) => {
+        $crate::__json_write!(@write $result $crate::WriteJson::write_json(&($($value)+), $writer));
+    };
+
+    (@separator $writer:ident $result:ident) => {};
+    (@separator $writer:ident $result:ident ,) => {
+        $crate::__json_write!(@write $result $writer.write_all(b","));
+    };
+
+    (@key $writer:ident $result:ident $key:literal) => {
+        $crate::__json_write!(@write $result $crate::WriteJson::write_json($key, $writer));
+        $crate::__json_write!(@write $result $writer.write_all(b":"));
+    };
+
+    // Arrays collect the tokens of one element at a time until the next comma.
+    (@array $writer:ident $result:ident [$($separator:tt)?] ()) => {};
+    (@array $writer:ident $result:ident [$($separator:tt)?] ($($rest:tt)+)) => {
+        $crate::__json_write!(@element $writer $result [$($separator)?] () ($($rest)+));
+    };
+    (@element $writer:ident $result:ident [$($separator:tt)?] ($($element:tt)+) (, $($rest:tt)*)) => {
+        $crate::__json_write!(@separator $writer $result $($separator)?);
+        $crate::__json_write!(@value $writer $result ($($element)+));
+        $crate::__json_write!(@array $writer $result [,] ($($rest)*));
+    };
+    (@element $writer:ident $result:ident [$($separator:tt)?] ($($element:tt)+) ()) => {
+        $crate::__json_write!(@separator $writer $result $($separator)?);
+        $crate::__json_write!(@value $writer $result ($($element)+));
+    };
+    (@element $writer:ident $result:ident [$($separator:tt)?] ($($element:tt)*) ($next:tt $($rest:tt)*)) => {
+        $crate::__json_write!(@element $writer $result [$($separator)?] ($($element)* $next) ($($rest)*));
+    };
+
+    // Objects collect the tokens of one property value at a time until the next comma.
+    (@object $writer:ident $result:ident [$($separator:tt)?] ()) => {};
+    (@object $writer:ident $result:ident [$($separator:tt)?] ($key:literal : $($rest:tt)+)) => {
+        $crate::__json_write!(@property $writer $result [$($separator)?] $key () ($($rest)+));
+    };
+    (@property $writer:ident $result:ident [$($separator:tt)?] $key:literal ($($value:tt)+) (, $($rest:tt)*)) => {
+        $crate::__json_write!(@separator $writer $result $($separator)?);
+        $crate::__json_write!(@key $writer $result $key);
+        $crate::__json_write!(@value $writer $result ($($value)+));
+        $crate::__json_write!(@object $writer $result [,] ($($rest)*));
+    };
+    (@property $writer:ident $result:ident [$($separator:tt)?] $key:literal ($($value:tt)+) ()) => {
+        $crate::__json_write!(@separator $writer $result $($separator)?);
+        $crate::__json_write!(@key $writer $result $key);
+        $crate::__json_write!(@value $writer $result ($($value)+));
+    };
+    (@property $writer:ident $result:ident [$($separator:tt)?] $key:literal ($($value:tt)*) ($next:tt $($rest:tt)*)) => {
+        $crate::__json_write!(@property $writer $result [$($separator)?] $key ($($value)* $next) ($($rest)*));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::JsonNode;
+
+    #[test]
+    fn json_write_object_with_interpolated_values() {
+        let name = "Jason".to_owned();
+        let age = 30;
+        let numbers = vec![1, 2, 3];
+        let height: Option<f64> = None;
+
+        let mut buffer = Vec::new();
+
+        json_write!(buffer, {
+            "name": name,
+            "age": age + 1,
+            "isMale": true,
+            "height": height,
+            "numbers": numbers,
+            "child": { "name": "Jasmine", "tags": [] },
+            "empty": {}
+        }).unwrap();
+
+        let json = String::from_utf8(buffer).unwrap();
+        let expected = r#"{"name":"Jason","age":31,"isMale":true,"height":null,"numbers":[1,2,3],"child":{"name":"Jasmine","tags":[]},"empty":{}}"#;
+
+        assert_eq!(json, expected);
+        assert_eq!(JsonNode::parse(&json).unwrap().to_json_string(), expected);
+    }
+
+    #[test]
+    fn json_write_scalars_and_arrays() {
+        let mut buffer = Vec::new();
+        json_write!(&mut buffer, [null, 1.5, "two", [3, null],]).unwrap();
+        assert_eq!(buffer, b"[null,1.5,\"two\",[3,null]]");
+
+        let mut buffer = Vec::new();
+        json_write!(buffer, null).unwrap();
+        assert_eq!(buffer, b"null");
+    }
+
+    #[test]
+    fn json_write_question_mark_applies_to_the_caller() {
+        fn write_user(buffer: &mut Vec<u8>, name: Option<&str>) -> Option<usize> {
+            json_write!(*buffer, { "name": name? }).ok()?;
+            Some(buffer.len())
+        }
+
+        let mut buffer = Vec::new();
+
+        // The JSON is streamed, so whatever came before the `?` has already been written.
+        assert_eq!(write_user(&mut buffer, None), None);
+        assert_eq!(buffer, br#"{"name":"#);
+
+        buffer.clear();
+        assert_eq!(write_user(&mut buffer, Some("Jason")), Some(16));
+        assert_eq!(buffer, br#"{"name":"Jason"}"#);
+    }
+
+    #[test]
+    fn json_write_stops_at_the_first_error() {
+        use std::io::{self, Write};
+
+        struct Full;
+
+        impl Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        fn evaluate(evaluated: &mut bool) -> i32 {
+            *evaluated = true;
+            2
+        }
+
+        let mut evaluated = false;
+        let result = json_write!(Full, [1, evaluate(&mut evaluated)]);
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::WriteZero);
+        assert!(!evaluated);
+    }
+}
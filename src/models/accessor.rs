@@ -0,0 +1,34 @@
+/// One step into a node tree, which is a property name for objects and an index for arrays, as taken by `JsonNode::get_by`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{Accessor, JsonNode};
+/// 
+/// let node = JsonNode::parse(r#"{"children": [{"name": "Jason Jr."}]}"#).unwrap();
+/// let steps = [Accessor::Key("children"), Accessor::Index(0), Accessor::Key("name")];
+/// 
+/// let name = steps.into_iter().try_fold(&node, |node, accessor| node.get_by(accessor));
+/// 
+/// assert_eq!(name, Some(&JsonNode::String("Jason Jr.".to_owned())));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Accessor<'a> {
+    /// The name of a property of an object.
+    Key(&'a str),
+
+    /// The index of an element of an array.
+    Index(usize),
+}
+
+impl<'a> From<&'a str> for Accessor<'a> {
+    fn from(key: &'a str) -> Self {
+        Accessor::Key(key)
+    }
+}
+
+impl From<usize> for Accessor<'_> {
+    fn from(index: usize) -> Self {
+        Accessor::Index(index)
+    }
+}
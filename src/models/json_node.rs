@@ -1,21 +1,43 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::mem;
 
-use crate::models::JsonPropertyMap;
-use crate::parsing::JsonNodeParser;
-use crate::utils::SurroundWith;
-use crate::Result;
+use crate::models::{Accessor, JsonPropertyMap, JsonValue};
+use crate::parsing::{JsonNodeParser, LossyNumber, ParseOptions, SpanTable};
+use crate::serializing::{JsonNodeSerializer, SerializeOptions};
+use crate::{FromJsonNode, JsonNodeError, Result, ToJsonNode};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub enum JsonNode {
     Object(JsonPropertyMap),
     Array(Vec<JsonNode>),
     String(String),
     Integer(i64),
+
+    /// An integer too large for `i64` which still fits in a `u64`, such as a 64-bit unsigned ID.
+    /// Integers which fit in an `i64` are always parsed as `JsonNode::Integer`.
+    UInteger(u64),
     Float(f64),
     Boolean(bool),
     Null,
+
+    /// A number kept exactly as it was written in the JSON.
+    /// Only produced when parsing with `ParseOptions::preserve_number_text`.
+    RawNumber(String),
+
+    /// A value which is absent, as opposed to explicitly `null`.
+    /// Properties whose value is `Undefined` are left out when serializing, and anywhere else it is written as `null`.
+    #[cfg(feature = "undefined")]
+    Undefined,
 }
 
+/// Returned by `JsonNode::get_or_undefined` when there is no property to borrow.
+#[cfg(feature = "undefined")]
+static UNDEFINED: JsonNode = JsonNode::Undefined;
+
 impl JsonNode {
     /// Parse a JSON string slice into a `JsonNode` structure.
     /// 
@@ -38,664 +60,4153 @@ impl JsonNode {
     /// 
     /// assert_eq!(node_tree, expected);
     /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// A UTF-8 byte order mark at the very start of `json` is skipped.
     pub fn parse(json: &str) -> Result<JsonNode> {
-        JsonNodeParser::parse_node(json, None)
+        Self::parse_with_options(json, &ParseOptions::default())
     }
 
-    /// Checks if the node is the JsonNode::Object discriminant.
+    /// Parse a JSON string slice into a `JsonNode` structure using the given options.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
+    /// * `options` - Controls how strictly the JSON grammar is followed.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// use json_node::{JsonNode, ParseOptions};
     /// 
-    /// // Create an object node.
-    /// let object_node = JsonNode::Object(JsonPropertyMap::new());
-    /// // Create a non-object node.
-    /// let non_object_node = JsonNode::Null;
+    /// // Leading zeros aren't allowed by the JSON grammar.
+    /// assert!(JsonNode::parse("01").is_err());
     /// 
-    /// assert!(object_node.is_object());
-    /// assert!(!non_object_node.is_object())
+    /// // But they can be allowed by parsing numbers leniently.
+    /// let options = ParseOptions {
+    ///     lenient_numbers: true,
+    ///     ..ParseOptions::default()
+    /// };
+    /// 
+    /// assert_eq!(JsonNode::parse_with_options("01", &options).unwrap(), JsonNode::Integer(1));
     /// ```
-    pub fn is_object(&self) -> bool {
-        match self {
-            JsonNode::Object(_) => true,
-            _ => false,
-        }
+    /// 
+    /// # Remarks
+    /// 
+    /// A UTF-8 byte order mark at the very start of `json` is skipped.
+    pub fn parse_with_options(json: &str, options: &ParseOptions) -> Result<JsonNode> {
+        JsonNodeParser::parse(JsonNodeParser::strip_byte_order_mark(json), options)
     }
 
-    /// Checks if the node is the JsonNode::Array discriminant.
+    /// Parse a JSON string slice into a `JsonNode` structure, falling back to `default` when there is nothing to parse or it can't be parsed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
+    /// * `default` - The node returned when `json` is empty, only white space or invalid.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::JsonNode;
-    /// // Create an array node.
-    /// let array_node = JsonNode::Array(Vec::new());
-    /// // Create a non-array node.
-    /// let non_array_node = JsonNode::Null;
+    /// use json_node::{JsonNode, JsonPropertyMap};
     /// 
-    /// assert!(array_node.is_array());
-    /// assert!(!non_array_node.is_array())
+    /// let empty_object = JsonNode::Object(JsonPropertyMap::new());
+    /// 
+    /// assert_eq!(JsonNode::parse_or("  ", empty_object.clone()), empty_object);
+    /// assert_eq!(JsonNode::parse_or("[1", empty_object.clone()), empty_object);
+    /// assert_eq!(JsonNode::parse_or("10", empty_object), JsonNode::Integer(10));
     /// ```
-    pub fn is_array(&self) -> bool {
-        match self {
-            JsonNode::Array(_) => true,
-            _ => false,
-        }
+    /// 
+    /// # Remarks
+    /// 
+    /// This is `JsonNode::parse_or_with_options` with `ParseOptions::fallback_on_error` set.
+    /// Use that with the default options to fall back only for empty JSON and report invalid JSON instead.
+    pub fn parse_or(json: &str, default: JsonNode) -> JsonNode {
+        let options = ParseOptions {
+            fallback_on_error: true,
+            ..ParseOptions::default()
+        };
+
+        Self::parse_or_with_options(json, default, &options).expect("parsing falls back to the default on every error")
     }
 
-    /// Extracts the `JsonPropertyMap` contained inside the node if it is the `JsonNode::Object` discriminant.
+    /// Parse a JSON string slice into a `JsonNode` structure using the given options, falling back to `default` when there is nothing to parse.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
+    /// * `default` - The node returned when `json` is empty or only white space, or when it is invalid and `ParseOptions::fallback_on_error` is set.
+    /// * `options` - Controls what is accepted while parsing and whether invalid JSON falls back to `default`.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// use json_node::{JsonNode, JsonPropertyMap, ParseOptions};
     /// 
-    /// // Create an object node.
-    /// let object_node = JsonNode::Object(JsonPropertyMap::new());
+    /// let empty_object = JsonNode::Object(JsonPropertyMap::new());
     /// 
-    /// // Extract `JsonPropertyMap`.
-    /// let as_object_some = object_node.as_object(); // Option<&JsonPropertyMap>
+    /// assert_eq!(JsonNode::parse_or_with_options("", empty_object.clone(), &ParseOptions::default()), Ok(empty_object.clone()));
+    /// assert!(JsonNode::parse_or_with_options("[1", empty_object.clone(), &ParseOptions::default()).is_err());
     /// 
-    /// assert_eq!(as_object_some, Some(&JsonPropertyMap::new()));
+    /// let options = ParseOptions {
+    ///     fallback_on_error: true,
+    ///     ..ParseOptions::default()
+    /// };
     /// 
-    /// // Create a non-object node.
-    /// let non_object_node = JsonNode::Null;
+    /// assert_eq!(JsonNode::parse_or_with_options("[1", empty_object.clone(), &options), Ok(empty_object));
+    /// ```
     /// 
-    /// // Fail to extract `JsonPropertyMap`.
-    /// let as_object_none = non_object_node.as_object();
+    /// # Errors
     /// 
-    /// assert_eq!(as_object_none, None);
-    /// ```
-    pub fn as_object(&self) -> Option<&JsonPropertyMap> {
-        match self {
-            JsonNode::Object(object) => Some(object),
-            _ => None,
+    /// Returns the error `JsonNode::parse_with_options` would return for non-empty `json`, unless `ParseOptions::fallback_on_error` is set.
+    pub fn parse_or_with_options(json: &str, default: JsonNode, options: &ParseOptions) -> Result<JsonNode> {
+        match Self::parse_with_options(json, options) {
+            Err(JsonNodeError::EmptyJson(None)) => Ok(default),
+            Err(_) if options.fallback_on_error => Ok(default),
+            result => result,
         }
     }
 
-    /// Extracts the `Vec<JsonNode>` contained inside the node if it is the `JsonNode::Array` discriminant.
+    /// Parse a JSON string slice into a `JsonNode` structure and record the byte range every value was written at.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// // Create an array node.
-    /// let array_node = JsonNode::Array(Vec::new());
+    /// let json = r#"{"name": "Jason", "numbers": [1, 2]}"#;
+    /// let (node, spans) = JsonNode::parse_with_spans(json).unwrap();
     /// 
-    /// // Extract `Vec<JsonNode>`.
-    /// let as_array_some = array_node.as_array(); // Option<&Vec<JsonNode>>
+    /// assert_eq!(node, JsonNode::parse(json).unwrap());
+    /// assert_eq!(&json[spans.get("/name").unwrap()], "\"Jason\"");
+    /// assert_eq!(&json[spans.get("/numbers/1").unwrap()], "2");
+    /// ```
     /// 
-    /// assert_eq!(as_array_some, Some(&Vec::new()));
+    /// # Remarks
     /// 
-    /// // Create a non-array node.
-    /// let non_array_node = JsonNode::Null;
+    /// The spans are keyed by JSON Pointers like those `JsonNode::pointer` accepts, which makes it possible to jump from a value to where it is in the source.
+    /// A UTF-8 byte order mark at the very start of `json` is skipped, but the spans are still counted from the start of `json`.
     /// 
-    /// // Fail to extract `Vec<JsonNode>`.
-    /// let as_array_none = non_array_node.as_array();
+    /// # Errors
     /// 
-    /// assert_eq!(as_array_none, None);
-    /// ```
-    pub fn as_array(&self) -> Option<&Vec<JsonNode>> {
-        match self {
-            JsonNode::Array(array) => Some(array),
-            _ => None,
-        }
+    /// Returns the error `JsonNode::parse` would return for `json`.
+    pub fn parse_with_spans(json: &str) -> Result<(JsonNode, SpanTable)> {
+        JsonNodeParser::parse_with_spans(json, &ParseOptions::default())
     }
 
-    /// Extracts the `JsonPropertyMap` contained inside the node if it is the `JsonNode::Object` discriminant as a mutable value.
+    /// Parse a JSON string into a node tree, also listing every number which lost precision by being stored as an `f64`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON you wish to be parsed.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// use json_node::JsonNode;
     /// 
-    /// // Create an object node.
-    /// let mut object_node = JsonNode::Object(JsonPropertyMap::new());
+    /// let json = r#"{"price": 0.1, "ratio": 0.12345678901234567, "count": 3}"#;
+    /// let (node, lossy) = JsonNode::parse_with_diagnostics(json).unwrap();
     /// 
-    /// // Extract `JsonPropertyMap`.
-    /// let as_object_some = object_node.as_object_mut(); // Option<&mut JsonPropertyMap>
+    /// assert_eq!(node, JsonNode::parse(json).unwrap());
+    /// assert_eq!(lossy.len(), 1);
+    /// assert_eq!(lossy[0].pointer, "/ratio");
+    /// ```
     /// 
-    /// assert_eq!(as_object_some, Some(&mut JsonPropertyMap::new()));
+    /// # Remarks
     /// 
-    /// // Create a non-object node.
-    /// let mut non_object_node = JsonNode::Null;
+    /// A number is lossy when the shortest text of its `f64` has different digits than the text it was written as, so `0.1` and `1e2` aren't lossy.
+    /// Integers which fit in an `i64` or a `u64` are never lossy, while larger ones usually are.
+    /// Unlike `JsonNode::parse`, such larger integers are parsed into floats and listed here instead of failing, as with `ParseOptions::oversized_integers_as_floats`.
+    /// Use `ParseOptions::preserve_number_text` to keep such numbers exactly.
     /// 
-    /// // Fail to extract `JsonPropertyMap`.
-    /// let as_object_none = non_object_node.as_object_mut();
+    /// # Errors
     /// 
-    /// assert_eq!(as_object_none, None);
-    /// ```
-    pub fn as_object_mut(&mut self) -> Option<&mut JsonPropertyMap> {
-        match self {
-            JsonNode::Object(object) => Some(object),
-            _ => None,
-        }
+    /// Returns the error `JsonNode::parse` would return for `json`, except for integers which are too large.
+    pub fn parse_with_diagnostics(json: &str) -> Result<(JsonNode, Vec<LossyNumber>)> {
+        let options = ParseOptions {
+            oversized_integers_as_floats: true,
+            ..ParseOptions::default()
+        };
+
+        JsonNodeParser::parse_with_diagnostics(json, &options)
     }
 
-    /// Extracts the `Vec<JsonNode>` contained inside the node if it is the `JsonNode::Array` discriminant as a mutable value.
+    /// Parse every JSON document in a string, where the documents follow each other separated only by optional white space.
+    /// This reads newline-delimited JSON (NDJSON) as well as values written back to back.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json` - The JSON documents you wish to be parsed.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// use json_node::JsonNode;
     /// 
-    /// // Create an array node.
-    /// let mut array_node = JsonNode::Array(Vec::new());
+    /// let documents = JsonNode::parse_many("{\"id\": 1}\n{\"id\": 2}\n").unwrap();
     /// 
-    /// // Extract `Vec<JsonNode>`.
-    /// let as_array_some = array_node.as_array_mut(); // Option<&mut Vec<JsonNode>>
+    /// assert_eq!(documents.len(), 2);
+    /// assert_eq!(documents[1].to_json_string(), r#"{"id":2}"#);
+    /// ```
     /// 
-    /// assert_eq!(as_array_some, Some(&mut Vec::new()));
+    /// # Remarks
     /// 
-    /// // Create a non-array node.
-    /// let mut non_array_node = JsonNode::Null;
+    /// Empty or white space only input gives no documents.
+    /// A UTF-8 byte order mark at the very start of `json` is skipped.
     /// 
-    /// // Fail to extract `JsonPropertyMap`.
-    /// let as_array_none = non_array_node.as_array_mut();
+    /// # Errors
     /// 
-    /// assert_eq!(as_array_none, None);
-    /// ```
-    pub fn as_array_mut(&mut self) -> Option<&mut Vec<JsonNode>> {
-        match self {
-            JsonNode::Array(array) => Some(array),
-            _ => None,
-        }
+    /// Returns `JsonNodeError::InDocument` holding the index of the first document which couldn't be parsed and the error `JsonNode::parse` would give for it.
+    /// Positions in the error are counted from the start of `json`.
+    pub fn parse_many(json: &str) -> Result<Vec<JsonNode>> {
+        JsonNodeParser::parse_many(JsonNodeParser::strip_byte_order_mark(json), &ParseOptions::default())
     }
 
-    /// Checks if the value is the `JsonNode::String` discriminant.
+    /// Parse JSON from raw bytes, checking that they are valid UTF-8 first.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes` - The UTF-8 encoded JSON you wish to be parsed, like the body of a response or the contents of a file.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use json_node::JsonNode;
-    /// 
-    /// let string_value = JsonNode::String("Hello World!".to_owned());
-    /// let non_string_value = JsonNode::Null;
+    /// use json_node::{JsonNode, JsonNodeError};
     /// 
-    /// assert!(string_value.is_string());
-    /// assert!(!non_string_value.is_string());
+    /// assert_eq!(JsonNode::from_slice(b"[\"caf\xC3\xA9\"]").unwrap().to_json_string(), r#"["café"]"#);
+    /// assert_eq!(JsonNode::from_slice(b"[\"caf\xE9\"]"), Err(JsonNodeError::InvalidUtf8 { position: 5 }));
     /// ```
-    pub fn is_string(&self) -> bool {
-        match self {
-            JsonNode::String(_) => true,
-            _ => false,
-        }
+    /// 
+    /// # Remarks
+    /// 
+    /// A UTF-8 byte order mark at the very start of `bytes` is skipped.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::InvalidUtf8` holding the byte offset of the first invalid sequence if `bytes` isn't UTF-8,
+    /// and otherwise the error `JsonNode::parse` would return.
+    pub fn from_slice(bytes: &[u8]) -> Result<JsonNode> {
+        Self::parse(std::str::from_utf8(bytes)?)
     }
 
-    /// Checks if the value is the `JsonNode::Integer` discriminant.
+    /// Creates a `JsonNode::String` holding text which looks like a number but must stay text, like the zero-padded ID `007`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `text` - The text to store, exactly as it should be written between the quotes.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let integer_value = JsonNode::Integer(42);
-    /// let non_integer_value = JsonNode::Null;
+    /// let node = JsonNode::Array(vec![JsonNode::raw_string("007"), JsonNode::Integer(7)]);
     /// 
-    /// assert!(integer_value.is_integer());
-    /// assert!(!non_integer_value.is_integer());
+    /// assert_eq!(node.to_json_string(), r#"["007",7]"#);
     /// ```
-    pub fn is_integer(&self) -> bool {
-        match self {
-            JsonNode::Integer(_) => true,
-            _ => false,
-        }
+    /// 
+    /// # Remarks
+    /// 
+    /// Strings are always written quoted and parsed back as strings, so the text is never turned into a number.
+    /// Use `JsonNode::RawNumber` instead for text which should be written as an unquoted number.
+    pub fn raw_string(text: &str) -> JsonNode {
+        JsonNode::String(text.to_owned())
     }
 
-    /// Checks if the value is the `JsonNode::Float` discriminant.
+    /// Create an empty object node.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let float_value = JsonNode::Float(3.14);
-    /// let non_float_value = JsonNode::Null;
+    /// let mut node = JsonNode::new_object();
+    /// node.as_object_mut().unwrap().add("name", JsonNode::String("Jason".to_owned()));
     /// 
-    /// assert!(float_value.is_float());
-    /// assert!(!non_float_value.is_float());
+    /// assert_eq!(node.to_json_string(), r#"{"name":"Jason"}"#);
     /// ```
-    pub fn is_float(&self) -> bool {
-        match self {
-            JsonNode::Float(_) => true,
-            _ => false,
-        }
+    pub fn new_object() -> JsonNode {
+        JsonNode::Object(JsonPropertyMap::new())
     }
 
-    /// Checks if the value is the `JsonNode::Boolean` discriminant.
+    /// Create an empty array node.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let bool_value = JsonNode::Boolean(true);
-    /// let non_bool_value = JsonNode::Null;
+    /// let mut node = JsonNode::new_array();
+    /// node.push_value(1).unwrap();
     /// 
-    /// assert!(bool_value.is_bool());
-    /// assert!(!non_bool_value.is_bool());
+    /// assert_eq!(node.to_json_string(), "[1]");
     /// ```
-    pub fn is_bool(&self) -> bool {
-        match self {
-            JsonNode::Boolean(_) => true,
-            _ => false,
-        }
+    pub fn new_array() -> JsonNode {
+        JsonNode::Array(Vec::new())
     }
 
-    /// Checks if the value is the `JsonNode::Null` discriminant.
+    /// Create an empty object node with room for `capacity` properties.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `capacity` - The number of properties to preallocate room for.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let null_value = JsonNode::Null;
-    /// let non_null_value = JsonNode::Integer(42);
+    /// let node = JsonNode::object_with_capacity(10);
     /// 
-    /// assert!(null_value.is_null());
-    /// assert!(!non_null_value.is_null());
+    /// assert!(node.as_object().unwrap().capacity() >= 10);
     /// ```
-    pub fn is_null(&self) -> bool {
-        match self {
-            JsonNode::Null => true,
-            _ => false,
-        }
+    pub fn object_with_capacity(capacity: usize) -> JsonNode {
+        JsonNode::Object(JsonPropertyMap::with_capacity(capacity))
     }
 
-    /// Extracts the inner `str` contained inside the node if it is the `JsonNode::String` discriminant.
+    /// Create an empty array node with room for `capacity` elements.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `capacity` - The number of elements to preallocate room for.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let string_value = JsonNode::String("Hello World!".to_owned());
-    /// let non_string_value = JsonNode::Null;
+    /// let node = JsonNode::array_with_capacity(10);
     /// 
-    /// assert_eq!(string_value.as_string(), Some("Hello World!"));
-    /// assert_eq!(non_string_value.as_string(), None);
+    /// assert!(node.as_array().unwrap().capacity() >= 10);
     /// ```
-    pub fn as_string(&self) -> Option<&str> {
-        match self {
-            JsonNode::String(value) => Some(value),
-            _ => None,
-        }
+    pub fn array_with_capacity(capacity: usize) -> JsonNode {
+        JsonNode::Array(Vec::with_capacity(capacity))
     }
 
-    /// Extracts the inner `i64` contained inside the node if it is the `JsonNode::Integer` discriminant.
+    /// Gets a lowercase name describing which kind of node this is.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let integer_value = JsonNode::Integer(42);
-    /// let non_integer_value = JsonNode::Null;
-    /// 
-    /// assert_eq!(integer_value.as_integer(), Some(&42));
-    /// assert_eq!(non_integer_value.as_integer(), None);
+    /// assert_eq!(JsonNode::Array(Vec::new()).type_name(), "array");
+    /// assert_eq!(JsonNode::Boolean(true).type_name(), "boolean");
     /// ```
-    pub fn as_integer(&self) -> Option<&i64> {
+    pub fn type_name(&self) -> &'static str {
         match self {
-            JsonNode::Integer(value) => Some(value),
-            _ => None,
+            JsonNode::Object(_) => "object",
+            JsonNode::Array(_) => "array",
+            JsonNode::String(_) => "string",
+            JsonNode::Integer(_) | JsonNode::UInteger(_) => "integer",
+            JsonNode::Float(_) => "float",
+            JsonNode::Boolean(_) => "boolean",
+            JsonNode::Null => "null",
+            JsonNode::RawNumber(_) => "number",
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => "undefined",
         }
     }
 
-    /// Extracts the inner `f64` contained inside the node if it is the `JsonNode::Float` discriminant.
+    /// Gets the `type_name` of every element if the node is the `JsonNode::Array` discriminant.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let float_value = JsonNode::Float(3.14);
-    /// let non_float_value = JsonNode::Null;
+    /// let array_node = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Null]);
     /// 
-    /// assert_eq!(float_value.as_float(), Some(&3.14));
-    /// assert_eq!(non_float_value.as_float(), None);
+    /// assert_eq!(array_node.array_element_types(), Some(vec!["integer", "null"]));
+    /// assert_eq!(JsonNode::Null.array_element_types(), None);
     /// ```
-    pub fn as_float(&self) -> Option<&f64> {
-        match self {
-            JsonNode::Float(value) => Some(value),
-            _ => None,
-        }
+    pub fn array_element_types(&self) -> Option<Vec<&'static str>> {
+        self.as_array()
+            .map(|array| array.iter().map(|element| element.type_name()).collect())
     }
 
-    /// Extracts the inner `bool` contained inside the node if it is the `JsonNode::Boolean` discriminant.
+    /// Checks if the node is the `JsonNode::Array` discriminant and every element has the same `type_name`.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let bool_value = JsonNode::Boolean(true);
-    /// let non_bool_value = JsonNode::Null;
+    /// let homogeneous = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Integer(2)]);
+    /// let mixed = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Float(2.0)]);
     /// 
-    /// assert_eq!(bool_value.as_boolean(), Some(&true));
-    /// assert_eq!(non_bool_value.as_boolean(), None);
+    /// assert!(homogeneous.is_homogeneous_array());
+    /// assert!(!mixed.is_homogeneous_array());
     /// ```
-    pub fn as_boolean(&self) -> Option<&bool> {
-        match self {
-            JsonNode::Boolean(value) => Some(value),
-            _ => None,
+    /// 
+    /// # Remarks
+    /// 
+    /// An empty array is considered homogeneous.
+    pub fn is_homogeneous_array(&self) -> bool {
+        match self.array_element_types() {
+            Some(types) => types.windows(2).all(|pair| pair[0] == pair[1]),
+            None => false,
         }
     }
 
-    /// Extracts the inner `mut str` contained inside the node if it is the `JsonNode::String` discriminant.
+    /// Counts how many nodes of each `type_name` the tree has, including the root and every object and array.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use json_node::JsonNode;
     /// 
-    /// let mut string_value = JsonNode::String("Hello World!".to_owned());
-    /// let mut non_string_value = JsonNode::Null;
+    /// let node = JsonNode::parse(r#"{"ids": [1, 2, 3], "name": "Jason", "parent": null}"#).unwrap();
+    /// let histogram = node.type_histogram();
     /// 
-    /// assert_eq!(string_value.as_string_mut(), Some("Hello World!".to_string().as_mut_str()));
-    /// assert_eq!(non_string_value.as_string_mut(), None);
+    /// assert_eq!(histogram["integer"], 3);
+    /// assert_eq!(histogram["object"], 1);
+    /// assert_eq!(histogram.get("float"), None);
     /// ```
-    pub fn as_string_mut(&mut self) -> Option<&mut str> {
+    /// 
+    /// # Remarks
+    /// 
+    /// Kinds which don't appear in the tree have no entry rather than a count of zero.
+    pub fn type_histogram(&self) -> HashMap<&'static str, usize> {
+        let mut histogram = HashMap::new();
+        count_types_in(self, &mut histogram);
+        histogram
+    }
+
+    /// Checks if the node is the JsonNode::Object discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// // Create an object node.
+    /// let object_node = JsonNode::Object(JsonPropertyMap::new());
+    /// // Create a non-object node.
+    /// let non_object_node = JsonNode::Null;
+    /// 
+    /// assert!(object_node.is_object());
+    /// assert!(!non_object_node.is_object())
+    /// ```
+    pub fn is_object(&self) -> bool {
+        matches!(self, JsonNode::Object(_))
+    }
+
+    /// Checks if the node is the JsonNode::Array discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// // Create an array node.
+    /// let array_node = JsonNode::Array(Vec::new());
+    /// // Create a non-array node.
+    /// let non_array_node = JsonNode::Null;
+    /// 
+    /// assert!(array_node.is_array());
+    /// assert!(!non_array_node.is_array())
+    /// ```
+    pub fn is_array(&self) -> bool {
+        matches!(self, JsonNode::Array(_))
+    }
+
+    /// Extracts the `JsonPropertyMap` contained inside the node if it is the `JsonNode::Object` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// // Create an object node.
+    /// let object_node = JsonNode::Object(JsonPropertyMap::new());
+    /// 
+    /// // Extract `JsonPropertyMap`.
+    /// let as_object_some = object_node.as_object(); // Option<&JsonPropertyMap>
+    /// 
+    /// assert_eq!(as_object_some, Some(&JsonPropertyMap::new()));
+    /// 
+    /// // Create a non-object node.
+    /// let non_object_node = JsonNode::Null;
+    /// 
+    /// // Fail to extract `JsonPropertyMap`.
+    /// let as_object_none = non_object_node.as_object();
+    /// 
+    /// assert_eq!(as_object_none, None);
+    /// ```
+    pub fn as_object(&self) -> Option<&JsonPropertyMap> {
         match self {
-            JsonNode::String(value) => Some(value),
+            JsonNode::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Extracts the `Vec<JsonNode>` contained inside the node if it is the `JsonNode::Array` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// // Create an array node.
+    /// let array_node = JsonNode::Array(Vec::new());
+    /// 
+    /// // Extract `Vec<JsonNode>`.
+    /// let as_array_some = array_node.as_array(); // Option<&Vec<JsonNode>>
+    /// 
+    /// assert_eq!(as_array_some, Some(&Vec::new()));
+    /// 
+    /// // Create a non-array node.
+    /// let non_array_node = JsonNode::Null;
+    /// 
+    /// // Fail to extract `Vec<JsonNode>`.
+    /// let as_array_none = non_array_node.as_array();
+    /// 
+    /// assert_eq!(as_array_none, None);
+    /// ```
+    pub fn as_array(&self) -> Option<&Vec<JsonNode>> {
+        match self {
+            JsonNode::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Converts every element of an array node into `T`, giving a typed `Vec<T>`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonNodeError};
+    /// 
+    /// let numbers = JsonNode::parse("[1, 2, 3]").unwrap();
+    /// let mixed = JsonNode::parse(r#"[1, 2, "three"]"#).unwrap();
+    /// 
+    /// assert_eq!(numbers.as_vec_of::<i64>(), Ok(vec![1, 2, 3]));
+    /// assert_eq!(mixed.as_vec_of::<i64>(), Err(JsonNodeError::InElement {
+    ///     index: 2,
+    ///     error: Box::new(JsonNodeError::TypeMismatch { expected: "integer", found: "string" }),
+    /// }));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array,
+    /// and `JsonNodeError::InElement` holding the index and error of the first element which can't be converted into `T`.
+    pub fn as_vec_of<T: FromJsonNode>(&self) -> Result<Vec<T>> {
+        let array = self.as_array().ok_or(JsonNodeError::TypeMismatch {
+            expected: "array",
+            found: self.type_name(),
+        })?;
+
+        array
+            .iter()
+            .enumerate()
+            .map(|(index, element)| T::from_json_node(element).map_err(|error| JsonNodeError::InElement { index, error: Box::new(error) }))
+            .collect()
+    }
+
+    /// Extracts the `JsonPropertyMap` contained inside the node if it is the `JsonNode::Object` discriminant as a mutable value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// // Create an object node.
+    /// let mut object_node = JsonNode::Object(JsonPropertyMap::new());
+    /// 
+    /// // Extract `JsonPropertyMap`.
+    /// let as_object_some = object_node.as_object_mut(); // Option<&mut JsonPropertyMap>
+    /// 
+    /// assert_eq!(as_object_some, Some(&mut JsonPropertyMap::new()));
+    /// 
+    /// // Create a non-object node.
+    /// let mut non_object_node = JsonNode::Null;
+    /// 
+    /// // Fail to extract `JsonPropertyMap`.
+    /// let as_object_none = non_object_node.as_object_mut();
+    /// 
+    /// assert_eq!(as_object_none, None);
+    /// ```
+    pub fn as_object_mut(&mut self) -> Option<&mut JsonPropertyMap> {
+        match self {
+            JsonNode::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Copies the value out of the node as a `JsonValue` if it is a scalar, meaning anything but an object or an array.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonValue};
+    /// 
+    /// let node = JsonNode::parse(r#"[1, "two", [3]]"#).unwrap();
+    /// 
+    /// for element in node.as_array().unwrap() {
+    ///     match element.as_scalar() {
+    ///         Some(JsonValue::Integer(value)) => assert_eq!(value, 1),
+    ///         Some(JsonValue::String(value)) => assert_eq!(value, "two"),
+    ///         Some(_) => unreachable!(),
+    ///         None => assert!(element.is_array()),
+    ///     }
+    /// }
+    /// ```
+    pub fn as_scalar(&self) -> Option<JsonValue> {
+        match self {
+            JsonNode::Object(_) | JsonNode::Array(_) => None,
+            JsonNode::String(value) => Some(JsonValue::String(value.clone())),
+            JsonNode::Integer(value) => Some(JsonValue::Integer(*value)),
+            JsonNode::UInteger(value) => Some(JsonValue::UInteger(*value)),
+            JsonNode::Float(value) => Some(JsonValue::Float(*value)),
+            JsonNode::Boolean(value) => Some(JsonValue::Boolean(*value)),
+            JsonNode::Null => Some(JsonValue::Null),
+            JsonNode::RawNumber(text) => Some(JsonValue::RawNumber(text.clone())),
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => Some(JsonValue::Undefined),
+        }
+    }
+
+    /// Extracts the `Vec<JsonNode>` contained inside the node if it is the `JsonNode::Array` discriminant as a mutable value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// // Create an array node.
+    /// let mut array_node = JsonNode::Array(Vec::new());
+    /// 
+    /// // Extract `Vec<JsonNode>`.
+    /// let as_array_some = array_node.as_array_mut(); // Option<&mut Vec<JsonNode>>
+    /// 
+    /// assert_eq!(as_array_some, Some(&mut Vec::new()));
+    /// 
+    /// // Create a non-array node.
+    /// let mut non_array_node = JsonNode::Null;
+    /// 
+    /// // Fail to extract `JsonPropertyMap`.
+    /// let as_array_none = non_array_node.as_array_mut();
+    /// 
+    /// assert_eq!(as_array_none, None);
+    /// ```
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<JsonNode>> {
+        match self {
+            JsonNode::Array(array) => Some(array),
             _ => None,
         }
     }
 
-    /// Extracts the inner `mut i64` contained inside the node if it is the `JsonNode::Integer` discriminant.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use json_node::JsonNode;
-    /// 
-    /// let mut integer_value = JsonNode::Integer(42);
-    /// let mut non_integer_value = JsonNode::Null;
-    /// 
-    /// assert_eq!(integer_value.as_integer_mut(), Some(&mut 42));
-    /// assert_eq!(non_integer_value.as_integer_mut(), None);
-    /// ```
-    pub fn as_integer_mut(&mut self) -> Option<&mut i64> {
-        match self {
-            JsonNode::Integer(value) => Some(value),
-            _ => None,
-        }
+    /// Moves the `JsonPropertyMap` out of the node if it is the `JsonNode::Object` discriminant, without cloning it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let object_node = JsonNode::parse(r#"{"name": "Jason"}"#).unwrap();
+    /// let object = object_node.into_object().unwrap();
+    /// 
+    /// assert_eq!(object.get("name"), Some(&JsonNode::String("Jason".to_owned())));
+    /// 
+    /// // A node of any other kind is handed back unchanged.
+    /// assert_eq!(JsonNode::Null.into_object(), Err(JsonNode::Null));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the node itself if it isn't an object.
+    pub fn into_object(mut self) -> std::result::Result<JsonPropertyMap, JsonNode> {
+        match self {
+            JsonNode::Object(ref mut object) => Ok(mem::take(object)),
+            node => Err(node),
+        }
+    }
+
+    /// Moves the `Vec<JsonNode>` out of the node if it is the `JsonNode::Array` discriminant, without cloning it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let array_node = JsonNode::parse("[1, 2]").unwrap();
+    /// 
+    /// assert_eq!(array_node.into_array(), Ok(vec![JsonNode::Integer(1), JsonNode::Integer(2)]));
+    /// 
+    /// // A node of any other kind is handed back unchanged.
+    /// assert_eq!(JsonNode::Integer(1).into_array(), Err(JsonNode::Integer(1)));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the node itself if it isn't an array.
+    pub fn into_array(mut self) -> std::result::Result<Vec<JsonNode>, JsonNode> {
+        match self {
+            JsonNode::Array(ref mut array) => Ok(mem::take(array)),
+            node => Err(node),
+        }
+    }
+
+    /// Checks if the value is the `JsonNode::String` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let string_value = JsonNode::String("Hello World!".to_owned());
+    /// let non_string_value = JsonNode::Null;
+    /// 
+    /// assert!(string_value.is_string());
+    /// assert!(!non_string_value.is_string());
+    /// ```
+    pub fn is_string(&self) -> bool {
+        matches!(self, JsonNode::String(_))
+    }
+
+    /// Checks if the value is the `JsonNode::Integer` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let integer_value = JsonNode::Integer(42);
+    /// let non_integer_value = JsonNode::Null;
+    /// 
+    /// assert!(integer_value.is_integer());
+    /// assert!(!non_integer_value.is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        matches!(self, JsonNode::Integer(_))
+    }
+
+    /// Checks if the value is the `JsonNode::UInteger` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let uinteger_value = JsonNode::UInteger(u64::MAX);
+    /// let non_uinteger_value = JsonNode::Integer(42);
+    /// 
+    /// assert!(uinteger_value.is_uinteger());
+    /// assert!(!non_uinteger_value.is_uinteger());
+    /// ```
+    pub fn is_uinteger(&self) -> bool {
+        matches!(self, JsonNode::UInteger(_))
+    }
+
+    /// Checks if the value is the `JsonNode::Float` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let float_value = JsonNode::Float(3.14);
+    /// let non_float_value = JsonNode::Null;
+    /// 
+    /// assert!(float_value.is_float());
+    /// assert!(!non_float_value.is_float());
+    /// ```
+    pub fn is_float(&self) -> bool {
+        matches!(self, JsonNode::Float(_))
+    }
+
+    /// Checks if the value is the `JsonNode::Boolean` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let bool_value = JsonNode::Boolean(true);
+    /// let non_bool_value = JsonNode::Null;
+    /// 
+    /// assert!(bool_value.is_bool());
+    /// assert!(!non_bool_value.is_bool());
+    /// ```
+    pub fn is_bool(&self) -> bool {
+        matches!(self, JsonNode::Boolean(_))
+    }
+
+    /// Checks if the value is the `JsonNode::Null` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let null_value = JsonNode::Null;
+    /// let non_null_value = JsonNode::Integer(42);
+    /// 
+    /// assert!(null_value.is_null());
+    /// assert!(!non_null_value.is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        matches!(self, JsonNode::Null)
+    }
+
+    /// Checks if the value is the `JsonNode::Undefined` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// assert!(JsonNode::Undefined.is_undefined());
+    /// assert!(!JsonNode::Null.is_undefined());
+    /// ```
+    #[cfg(feature = "undefined")]
+    pub fn is_undefined(&self) -> bool {
+        matches!(self, JsonNode::Undefined)
+    }
+
+    /// Checks if the node is left out when it is the value of an object property.
+    pub(crate) fn is_omitted_from_objects(&self) -> bool {
+        match self {
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => true,
+            _ => false,
+        }
+    }
+
+    /// Extracts the inner `str` contained inside the node if it is the `JsonNode::String` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let string_value = JsonNode::String("Hello World!".to_owned());
+    /// let non_string_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(string_value.as_string(), Some("Hello World!"));
+    /// assert_eq!(non_string_value.as_string(), None);
+    /// ```
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            JsonNode::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `i64` contained inside the node if it is the `JsonNode::Integer` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let integer_value = JsonNode::Integer(42);
+    /// let non_integer_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(integer_value.as_integer(), Some(&42));
+    /// assert_eq!(non_integer_value.as_integer(), None);
+    /// ```
+    pub fn as_integer(&self) -> Option<&i64> {
+        match self {
+            JsonNode::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `u64` contained inside the node if it is the `JsonNode::UInteger` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let uinteger_value = JsonNode::UInteger(u64::MAX);
+    /// let non_uinteger_value = JsonNode::Integer(42);
+    /// 
+    /// assert_eq!(uinteger_value.as_uinteger(), Some(&u64::MAX));
+    /// assert_eq!(non_uinteger_value.as_uinteger(), None);
+    /// ```
+    pub fn as_uinteger(&self) -> Option<&u64> {
+        match self {
+            JsonNode::UInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `f64` contained inside the node if it is the `JsonNode::Float` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let float_value = JsonNode::Float(3.14);
+    /// let non_float_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(float_value.as_float(), Some(&3.14));
+    /// assert_eq!(non_float_value.as_float(), None);
+    /// ```
+    pub fn as_float(&self) -> Option<&f64> {
+        match self {
+            JsonNode::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `bool` contained inside the node if it is the `JsonNode::Boolean` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let bool_value = JsonNode::Boolean(true);
+    /// let non_bool_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(bool_value.as_boolean(), Some(&true));
+    /// assert_eq!(non_bool_value.as_boolean(), None);
+    /// ```
+    pub fn as_boolean(&self) -> Option<&bool> {
+        match self {
+            JsonNode::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of the node as an `f64` if it is a number.
+    /// 
+    /// Floats are returned as is, integers are converted and raw numbers are parsed on demand.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// assert_eq!(JsonNode::Float(3.14).as_f64(), Some(3.14));
+    /// assert_eq!(JsonNode::Integer(42).as_f64(), Some(42.0));
+    /// assert_eq!(JsonNode::RawNumber("1.10".to_owned()).as_f64(), Some(1.1));
+    /// assert_eq!(JsonNode::Null.as_f64(), None);
+    /// ```
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonNode::Float(value) => Some(*value),
+            JsonNode::Integer(value) => Some(*value as f64),
+            JsonNode::UInteger(value) => Some(*value as f64),
+            JsonNode::RawNumber(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `mut str` contained inside the node if it is the `JsonNode::String` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut string_value = JsonNode::String("Hello World!".to_owned());
+    /// let mut non_string_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(string_value.as_string_mut(), Some("Hello World!".to_string().as_mut_str()));
+    /// assert_eq!(non_string_value.as_string_mut(), None);
+    /// ```
+    pub fn as_string_mut(&mut self) -> Option<&mut str> {
+        match self {
+            JsonNode::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `mut i64` contained inside the node if it is the `JsonNode::Integer` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut integer_value = JsonNode::Integer(42);
+    /// let mut non_integer_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(integer_value.as_integer_mut(), Some(&mut 42));
+    /// assert_eq!(non_integer_value.as_integer_mut(), None);
+    /// ```
+    pub fn as_integer_mut(&mut self) -> Option<&mut i64> {
+        match self {
+            JsonNode::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `mut u64` contained inside the node if it is the `JsonNode::UInteger` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut uinteger_value = JsonNode::UInteger(u64::MAX);
+    /// let mut non_uinteger_value = JsonNode::Integer(42);
+    /// 
+    /// assert_eq!(uinteger_value.as_uinteger_mut(), Some(&mut u64::MAX));
+    /// assert_eq!(non_uinteger_value.as_uinteger_mut(), None);
+    /// ```
+    pub fn as_uinteger_mut(&mut self) -> Option<&mut u64> {
+        match self {
+            JsonNode::UInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `mut f64` contained inside the node if it is the `JsonNode::Float` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut float_value = JsonNode::Float(3.14);
+    /// let mut non_float_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(float_value.as_float_mut(), Some(&mut 3.14));
+    /// assert_eq!(non_float_value.as_float_mut(), None);
+    /// ```
+    pub fn as_float_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            JsonNode::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Extracts the inner `mut bool` contained inside the node if it is the `JsonNode::Boolean` discriminant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut bool_value = JsonNode::Boolean(true);
+    /// let mut non_bool_value = JsonNode::Null;
+    /// 
+    /// assert_eq!(bool_value.as_boolean_mut(), Some(&mut true));
+    /// assert_eq!(non_bool_value.as_boolean_mut(), None);
+    /// ```
+    pub fn as_boolean_mut(&mut self) -> Option<&mut bool> {
+        match self {
+            JsonNode::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Checks if the node is the `JsonNode::Object` discriminant and has a property with the name `property_name`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - The name to check for.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let object_node = JsonNode::Object(JsonPropertyMap::from([
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]));
+    /// 
+    /// assert!(object_node.contains_key("age"));
+    /// assert!(!object_node.contains_key("name"));
+    /// assert!(!JsonNode::Null.contains_key("age"));
+    /// ```
+    pub fn contains_key(&self, property_name: &str) -> bool {
+        match self {
+            JsonNode::Object(object) => object.contains_property(property_name),
+            _ => false,
+        }
+    }
+
+    /// Checks if the node is the `JsonNode::Array` discriminant and has an element at `index`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `index` - The index to check for.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let array_node = JsonNode::Array(vec![JsonNode::Integer(42)]);
+    /// 
+    /// assert!(array_node.contains_index(0));
+    /// assert!(!array_node.contains_index(1));
+    /// assert!(!JsonNode::Null.contains_index(0));
+    /// ```
+    pub fn contains_index(&self, index: usize) -> bool {
+        match self {
+            JsonNode::Array(array) => index < array.len(),
+            _ => false,
+        }
+    }
+
+    /// Gets a property of an object node or an element of an array node, so a tree can be walked without matching on each node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `accessor` - The property name or index of the child, or anything converting into one like a `&str` or a `usize`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{Accessor, JsonNode};
+    /// 
+    /// let node = JsonNode::parse(r#"{"numbers": [1, 2, 3]}"#).unwrap();
+    /// let numbers = node.get_by("numbers").unwrap();
+    /// 
+    /// assert_eq!(numbers.get_by(Accessor::Index(1)), Some(&JsonNode::Integer(2)));
+    /// assert_eq!(numbers.get_by("length"), None);
+    /// assert_eq!(node.get_by(0), None);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Returns `None` if the child doesn't exist or the accessor is the wrong kind for the node, like an index into an object.
+    pub fn get_by<'a>(&self, accessor: impl Into<Accessor<'a>>) -> Option<&JsonNode> {
+        match (self, accessor.into()) {
+            (JsonNode::Object(object), Accessor::Key(key)) => object.get(key),
+            (JsonNode::Array(array), Accessor::Index(index)) => array.get(index),
+            _ => None,
+        }
+    }
+
+    /// Gets a mutable reference to a property of an object node or an element of an array node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `accessor` - The property name or index of the child, or anything converting into one like a `&str` or a `usize`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"numbers": [1, 2, 3]}"#).unwrap();
+    /// 
+    /// *node.get_by_mut("numbers").unwrap().get_by_mut(0).unwrap() = JsonNode::Null;
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"numbers":[null,2,3]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Returns `None` if the child doesn't exist or the accessor is the wrong kind for the node.
+    pub fn get_by_mut<'a>(&mut self, accessor: impl Into<Accessor<'a>>) -> Option<&mut JsonNode> {
+        match (self, accessor.into()) {
+            (JsonNode::Object(object), Accessor::Key(key)) => object.get_mut(key),
+            (JsonNode::Array(array), Accessor::Index(index)) => array.get_mut(index),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of a property if the node is the `JsonNode::Object` discriminant and has the property, otherwise `JsonNode::Undefined`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the property to get.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"middleName": null}"#).unwrap();
+    /// 
+    /// assert_eq!(node.get_or_undefined("middleName"), &JsonNode::Null);
+    /// assert_eq!(node.get_or_undefined("nickname"), &JsonNode::Undefined);
+    /// ```
+    #[cfg(feature = "undefined")]
+    pub fn get_or_undefined(&self, property_name: &str) -> &JsonNode {
+        match self {
+            JsonNode::Object(object) => object.get(property_name).unwrap_or(&UNDEFINED),
+            _ => &UNDEFINED,
+        }
+    }
+
+    /// Gets the node at a dotted path such as `children.0.name`.
+    /// 
+    /// Each segment is a property name when the current node is an object, and an index when the current node is an array.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - The segments leading to the node, separated by dots. An empty path is the node itself.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"children": [{"name": "Jason Jr."}]}"#).unwrap();
+    /// 
+    /// assert_eq!(node.get_path("children.0.name"), Some(&JsonNode::String("Jason Jr.".to_owned())));
+    /// assert_eq!(node.get_path("children.1.name"), None);
+    /// assert_eq!(node.get_path(""), Some(&node));
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<&JsonNode> {
+        if path.is_empty() {
+            return Some(self);
+        }
+
+        path.split('.').try_fold(self, |node, segment| match node {
+            JsonNode::Array(_) => node.get_by(segment.parse::<usize>().ok()?),
+            _ => node.get_by(segment),
+        })
+    }
+
+    /// Gets the node at a dotted path such as `children.0.name`, or `default` if there is no node at the path.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - The segments leading to the node, separated by dots, just like for `JsonNode::get_path`.
+    /// * `default` - The node returned when the path doesn't resolve.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let config = JsonNode::parse(r#"{"server": {"port": 8080}}"#).unwrap();
+    /// let fallback = JsonNode::String("localhost".to_owned());
+    /// 
+    /// assert_eq!(config.get_path_or("server.port", &fallback), &JsonNode::Integer(8080));
+    /// assert_eq!(config.get_path_or("server.host", &fallback), &fallback);
+    /// ```
+    pub fn get_path_or<'a>(&'a self, path: &str, default: &'a JsonNode) -> &'a JsonNode {
+        self.get_path(path).unwrap_or(default)
+    }
+
+    /// Gets the node at a dotted path and converts it into `T`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - The segments leading to the node, separated by dots. See `JsonNode::get_path`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonNodeError};
+    /// 
+    /// let node = JsonNode::parse(r#"{"name": "Jason", "age": 30}"#).unwrap();
+    /// 
+    /// let age: i64 = node.get_path_as("age").unwrap();
+    /// assert_eq!(age, 30);
+    /// 
+    /// assert_eq!(node.get_path_as::<i64>("height"), Err(JsonNodeError::PathNotFound("height".to_owned())));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::PathNotFound` if there is no node at the path,
+    /// and `JsonNodeError::TypeMismatch` if the node can't be converted into `T`
+    /// or a segment indexes an object by number or an array by name. See `JsonNode::pointer`.
+    pub fn get_path_as<T: FromJsonNode>(&self, path: &str) -> Result<T> {
+        if path.is_empty() {
+            return T::from_json_node(self);
+        }
+
+        let mut node = self;
+
+        for segment in path.split('.') {
+            node = child(node, segment)?.ok_or_else(|| JsonNodeError::PathNotFound(path.to_owned()))?;
+        }
+
+        T::from_json_node(node)
+    }
+
+    /// Gets the node at a JSON Pointer as defined by RFC 6901, such as `/children/0/name`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `pointer` - Segments each starting with `/`, where `~1` stands for `/` and `~0` for `~`. An empty pointer is the node itself.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonNodeError};
+    /// 
+    /// let node = JsonNode::parse(r#"{"children": [{"name": "Jason Jr."}], "a/b": 1}"#).unwrap();
+    /// 
+    /// assert_eq!(node.pointer("/children/0/name"), Ok(&JsonNode::String("Jason Jr.".to_owned())));
+    /// assert_eq!(node.pointer("/a~1b"), Ok(&JsonNode::Integer(1)));
+    /// assert_eq!(node.pointer("/children/name"), Err(JsonNodeError::TypeMismatch { expected: "object", found: "array" }));
+    /// assert_eq!(node.pointer("/children/1"), Err(JsonNodeError::PathNotFound("/children/1".to_owned())));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// A numeric segment on an object still finds a property with that name, like `/0` in `{"0": true}`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if a segment indexes an object by a number it has no property for,
+    /// names a property of an array, or continues past a value which isn't an object or an array.
+    /// Returns `JsonNodeError::PathNotFound` if a property or index doesn't exist, or if the pointer doesn't start with `/`.
+    pub fn pointer(&self, pointer: &str) -> Result<&JsonNode> {
+        if pointer.is_empty() {
+            return Ok(self);
+        }
+
+        let not_found = || JsonNodeError::PathNotFound(pointer.to_owned());
+        let segments = pointer.strip_prefix('/').ok_or_else(not_found)?;
+        let mut node = self;
+
+        for segment in segments.split('/') {
+            let segment = segment.replace("~1", "/").replace("~0", "~");
+            node = child(node, &segment)?.ok_or_else(not_found)?;
+        }
+
+        Ok(node)
+    }
+
+    /// Flattens the node tree into a single-level object whose keys are the paths to the leaves.
+    /// 
+    /// Object properties are joined with dots and array elements use bracketed indices, like `address.city` and `items[0]`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"address": {"city": "Oslo"}, "items": [1, {"id": 2}], "tags": []}"#).unwrap();
+    /// let flat = node.flatten();
+    /// 
+    /// assert_eq!(flat.to_json_string(), r#"{"address.city":"Oslo","items[0]":1,"items[1].id":2,"tags":[]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Empty objects and arrays are kept as `{}` and `[]` values, so no part of the tree is lost.
+    /// A node which isn't an object or array is flattened into a single property with an empty key.
+    /// Property names which contain dots or brackets themselves produce keys which can't be told apart from nested paths.
+    pub fn flatten(&self) -> JsonPropertyMap {
+        let mut flat = JsonPropertyMap::new();
+        flatten_into(self, String::new(), &mut flat);
+        flat
+    }
+
+    /// Rebuilds a node tree from a map whose keys are paths, as produced by `JsonNode::flatten`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `flat` - Maps dotted and bracketed paths like `address.city` and `items[0]` to the node at that path.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let flat = JsonPropertyMap::from([
+    ///     ("address.city".to_owned(), JsonNode::String("Oslo".to_owned())),
+    ///     ("items[2]".to_owned(), JsonNode::Integer(3)),
+    /// ]);
+    /// 
+    /// let node = JsonNode::unflatten(&flat).unwrap();
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"address":{"city":"Oslo"},"items":[null,null,3]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Arrays are made long enough for the largest index, and any index without a value is filled with `null`.
+    /// An index can be at most 1024 more than the number of entries in `flat`, which keeps a single key from allocating a huge array.
+    /// An empty map gives an empty object.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::ConflictingPath` if a path treats a node as a different kind of node than an earlier path did,
+    /// and `JsonNodeError::InvalidKey` if a key has a malformed index or an index beyond that limit.
+    pub fn unflatten(flat: &JsonPropertyMap) -> Result<JsonNode> {
+        if flat.is_empty() {
+            return Ok(JsonNode::Object(JsonPropertyMap::new()));
+        }
+
+        let mut root = UnflattenSlot::Vacant;
+
+        for (path, node) in flat.iter() {
+            let segments = parse_flat_path(path, flat.len() + MAX_UNFLATTEN_INDEX_GAP).ok_or_else(|| JsonNodeError::InvalidKey(path.clone()))?;
+
+            if !root.insert(&segments, node) {
+                return Err(JsonNodeError::ConflictingPath(path.clone()));
+            }
+        }
+
+        Ok(root.into_node())
+    }
+
+    /// Removes a property from the node if it is the `JsonNode::Object` discriminant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the property to be removed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let mut object_node = JsonNode::Object(JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]));
+    /// 
+    /// let age = object_node.remove("age").unwrap();
+    /// 
+    /// assert_eq!(age, JsonNode::Integer(42));
+    /// assert!(!object_node.as_object().unwrap().contains_property("age"));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an object, otherwise the same errors as `JsonPropertyMap::remove`.
+    pub fn remove(&mut self, property_name: &str) -> Result<JsonNode> {
+        match self {
+            JsonNode::Object(object) => object.remove(property_name),
+            _ => Err(JsonNodeError::TypeMismatch { expected: "object", found: self.type_name() }),
+        }
+    }
+
+    /// Appends a node to the end of the node if it is the `JsonNode::Array` discriminant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json_node` - The node to append, or anything converting into one like a `JsonValue`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonValue};
+    /// 
+    /// let mut array_node = JsonNode::Array(Vec::new());
+    /// array_node.push(JsonNode::Integer(1)).unwrap();
+    /// array_node.push(JsonValue::Null).unwrap();
+    /// 
+    /// assert_eq!(array_node.as_array().unwrap(), &vec![JsonNode::Integer(1), JsonNode::Null]);
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    pub fn push(&mut self, json_node: impl Into<JsonNode>) -> Result<()> {
+        match self {
+            JsonNode::Array(array) => {
+                array.push(json_node.into());
+                Ok(())
+            },
+            _ => Err(JsonNodeError::TypeMismatch { expected: "array", found: self.type_name() }),
+        }
+    }
+
+    /// Converts a value with `ToJsonNode` and appends it to the end of the node if it is the `JsonNode::Array` discriminant.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `value` - The value to append.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut array_node = JsonNode::Array(Vec::new());
+    /// array_node.push_value(1).unwrap();
+    /// array_node.push_value("two").unwrap();
+    /// array_node.push_value(vec![3.5]).unwrap();
+    /// 
+    /// assert_eq!(array_node.to_json_string(), r#"[1,"two",[3.5]]"#);
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    pub fn push_value(&mut self, value: impl ToJsonNode) -> Result<()> {
+        self.push(value.to_json_node())
+    }
+
+    /// Inserts a node at `index` if the node is the `JsonNode::Array` discriminant, shifting every later element one place to the right.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `index` - The position the node will have after it is inserted.
+    /// * `json_node` - The node to insert.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut array_node = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Integer(3)]);
+    /// array_node.insert_at(1, JsonNode::Integer(2)).unwrap();
+    /// 
+    /// assert_eq!(array_node.to_json_string(), "[1,2,3]");
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is greater than the length of the array.
+    pub fn insert_at(&mut self, index: usize, json_node: JsonNode) -> Result<()> {
+        match self {
+            JsonNode::Array(array) => {
+                array.insert(index, json_node);
+                Ok(())
+            },
+            _ => Err(JsonNodeError::TypeMismatch { expected: "array", found: self.type_name() }),
+        }
+    }
+
+    /// Shortens the node to its first `len` elements if it is the `JsonNode::Array` discriminant, like `Vec::truncate`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - The number of elements to keep. Nothing happens if the array isn't longer than this.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut array_node = JsonNode::parse("[1, 2, 3]").unwrap();
+    /// array_node.array_truncate(1).unwrap();
+    /// 
+    /// assert_eq!(array_node.to_json_string(), "[1]");
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    pub fn array_truncate(&mut self, len: usize) -> Result<()> {
+        match self {
+            JsonNode::Array(array) => {
+                array.truncate(len);
+                Ok(())
+            },
+            _ => Err(JsonNodeError::TypeMismatch { expected: "array", found: self.type_name() }),
+        }
+    }
+
+    /// Splits the node in two at `at` if it is the `JsonNode::Array` discriminant, like `Vec::split_off`.
+    /// The node keeps the elements before `at` and the elements from `at` onwards are returned as a new array node.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `at` - The index of the first element to move into the returned node.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut array_node = JsonNode::parse("[1, 2, 3]").unwrap();
+    /// let tail = array_node.array_split_off(1).unwrap();
+    /// 
+    /// assert_eq!(array_node.to_json_string(), "[1]");
+    /// assert_eq!(tail.to_json_string(), "[2,3]");
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `at` is greater than the length of the array.
+    pub fn array_split_off(&mut self, at: usize) -> Result<JsonNode> {
+        match self {
+            JsonNode::Array(array) => Ok(JsonNode::Array(array.split_off(at))),
+            _ => Err(JsonNodeError::TypeMismatch { expected: "array", found: self.type_name() }),
+        }
+    }
+
+    /// Converts the properties of the node into an array of `{"key": ..., "value": ...}` objects if it is the `JsonNode::Object` discriminant.
+    /// The entries keep the insertion order of the properties.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let object_node = JsonNode::parse(r#"{"name": "Jason", "age": 30}"#).unwrap();
+    /// let entries = object_node.entries_as_array().unwrap();
+    /// 
+    /// assert_eq!(entries.to_json_string(), r#"[{"key":"name","value":"Jason"},{"key":"age","value":30}]"#);
+    /// assert_eq!(JsonNode::Null.entries_as_array(), None);
+    /// ```
+    pub fn entries_as_array(&self) -> Option<JsonNode> {
+        let object = self.as_object()?;
+
+        let entries = object.iter()
+            .map(|(key, value)| JsonNode::Object(JsonPropertyMap::from([
+                ("key".to_owned(), JsonNode::String(key.clone())),
+                ("value".to_owned(), value.clone()),
+            ])))
+            .collect();
+
+        Some(JsonNode::Array(entries))
+    }
+
+    /// Builds an object node from an array of `{"key": ..., "value": ...}` objects, like the ones produced by `JsonNode::entries_as_array`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `array` - An array node with one entry object per property.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let entries = JsonNode::parse(r#"[{"key": "name", "value": "Jason"}, {"key": "age", "value": 30}]"#).unwrap();
+    /// let object_node = JsonNode::object_from_entries(&entries).unwrap();
+    /// 
+    /// assert_eq!(object_node.to_json_string(), r#"{"name":"Jason","age":30}"#);
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// * `JsonNodeError::TypeMismatch` if `array` isn't an array, an entry isn't an object or a `key` isn't a string.
+    /// * `JsonNodeError::KeyNotFound` if an entry is missing its `key` or `value` property.
+    /// * `JsonNodeError::MultiplePropertiesWithSameKey` if two entries have the same `key`.
+    pub fn object_from_entries(array: &JsonNode) -> Result<JsonNode> {
+        let entries = match array {
+            JsonNode::Array(entries) => entries,
+            _ => return Err(JsonNodeError::TypeMismatch { expected: "array", found: array.type_name() }),
+        };
+
+        let mut object = JsonPropertyMap::with_capacity(entries.len());
+
+        for entry in entries {
+            let entry = match entry {
+                JsonNode::Object(entry) => entry,
+                _ => return Err(JsonNodeError::TypeMismatch { expected: "object", found: entry.type_name() }),
+            };
+
+            let key = match entry.get("key") {
+                Some(JsonNode::String(key)) => key,
+                Some(key) => return Err(JsonNodeError::TypeMismatch { expected: "string", found: key.type_name() }),
+                None => return Err(JsonNodeError::KeyNotFound("key".to_owned())),
+            };
+
+            let value = entry.get("value")
+                .ok_or_else(|| JsonNodeError::KeyNotFound("value".to_owned()))?;
+
+            if object.contains_property(key) {
+                return Err(JsonNodeError::MultiplePropertiesWithSameKey(key.clone()));
+            }
+
+            object.add(key, value.clone());
+        }
+
+        Ok(JsonNode::Object(object))
+    }
+
+    /// Checks if two node trees are equal while skipping properties named in `ignore_keys` at any depth.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The node tree to compare against.
+    /// * `ignore_keys` - Names of properties which are skipped in every object on both sides.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let a = JsonNode::parse(r#"{"id": 1, "name": "Jason"}"#).unwrap();
+    /// let b = JsonNode::parse(r#"{"id": 2, "name": "Jason"}"#).unwrap();
+    /// 
+    /// assert!(a.eq_ignoring(&b, &["id"]));
+    /// assert!(!a.eq_ignoring(&b, &[]));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Apart from the skipped properties, this compares the same way as `==`, so the remaining properties must be in the same order.
+    pub fn eq_ignoring(&self, other: &JsonNode, ignore_keys: &[&str]) -> bool {
+        match (self, other) {
+            (JsonNode::Object(a), JsonNode::Object(b)) => {
+                let mut a = a.iter().filter(|(key, _)| !ignore_keys.contains(&key.as_str()));
+                let mut b = b.iter().filter(|(key, _)| !ignore_keys.contains(&key.as_str()));
+
+                loop {
+                    match (a.next(), b.next()) {
+                        (None, None) => return true,
+                        (Some((a_key, a_value)), Some((b_key, b_value))) => {
+                            if a_key != b_key || !a_value.eq_ignoring(b_value, ignore_keys) {
+                                return false;
+                            }
+                        },
+                        _ => return false,
+                    }
+                }
+            },
+            (JsonNode::Array(a), JsonNode::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.eq_ignoring(b, ignore_keys))
+            },
+            _ => self == other,
+        }
+    }
+
+    /// Compares two nodes using a total order, which makes it suitable for sorting mixed-type arrays.
+    /// This is the same order as the `Ord` implementation.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The node to compare against.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use json_node::JsonNode;
+    /// 
+    /// assert_eq!(JsonNode::Integer(2).total_cmp(&JsonNode::Float(1.5)), Ordering::Greater);
+    /// assert_eq!(JsonNode::Null.total_cmp(&JsonNode::Boolean(false)), Ordering::Less);
+    /// assert_eq!(JsonNode::String("a".to_owned()).total_cmp(&JsonNode::Integer(100)), Ordering::Greater);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Nodes of different kinds are ordered as `undefined` < `null` < booleans < numbers < strings < arrays < objects.
+    /// 
+    /// * Booleans order `false` before `true`.
+    /// * Integers, unsigned integers, floats and raw numbers share one number line and compare by value.
+    ///   When numbers have the same value, integers come before unsigned integers, which come before floats, which come before raw numbers, and raw numbers are ordered by their text.
+    ///   Floats are compared like `PartialEq` compares them, so `-0.0` equals `0.0` and `NaN` equals `NaN` and comes after every other number.
+    /// * Strings compare lexicographically by their characters.
+    /// * Arrays compare element by element, and a shorter array comes first when it is a prefix of the longer one.
+    /// * Objects compare property by property in insertion order, first by name and then by node, and a shorter object comes first when it is a prefix of the longer one.
+    pub fn total_cmp(&self, other: &JsonNode) -> Ordering {
+        fn rank(node: &JsonNode) -> u8 {
+            match node {
+                #[cfg(feature = "undefined")]
+                JsonNode::Undefined => 0,
+                JsonNode::Null => 1,
+                JsonNode::Boolean(_) => 2,
+                JsonNode::Integer(_) | JsonNode::UInteger(_) | JsonNode::Float(_) | JsonNode::RawNumber(_) => 3,
+                JsonNode::String(_) => 4,
+                JsonNode::Array(_) => 5,
+                JsonNode::Object(_) => 6,
+            }
+        }
+
+        match (self, other) {
+            (JsonNode::Null, JsonNode::Null) => Ordering::Equal,
+            (JsonNode::Boolean(a), JsonNode::Boolean(b)) => a.cmp(b),
+            (a, b) if rank(a) == 3 && rank(b) == 3 => compare_numbers(a, b),
+            (JsonNode::String(a), JsonNode::String(b)) => a.cmp(b),
+            (JsonNode::Array(a), JsonNode::Array(b)) => {
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.total_cmp(b))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| a.len().cmp(&b.len()))
+            },
+            (JsonNode::Object(a), JsonNode::Object(b)) => {
+                a.iter()
+                    .zip(b.iter())
+                    .map(|((a_key, a_value), (b_key, b_value))| a_key.cmp(b_key).then_with(|| a_value.total_cmp(b_value)))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| a.len().cmp(&b.len()))
+            },
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+
+    /// Compares two node trees like `==` does, except that numbers only have to be within `epsilon` of each other and object properties may be in any order.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The node tree to compare against.
+    /// * `epsilon` - The largest difference allowed between two numbers when either of them is a float.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let a = JsonNode::parse(r#"{"x": 0.30000000000000004, "y": 2}"#).unwrap();
+    /// let b = JsonNode::parse(r#"{"y": 2.0, "x": 0.3}"#).unwrap();
+    /// 
+    /// assert!(a.approx_eq(&b, 1e-9));
+    /// assert!(!a.approx_eq(&b, 0.0));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Integers, unsigned integers, floats and raw numbers are all compared by value, so `2` equals `2.0`.
+    /// Two integers must be exactly equal, while a float is compared to any other number within `epsilon`.
+    /// Like `PartialEq`, `NaN` equals `NaN`, and infinities only equal infinities with the same sign.
+    /// Array elements are still compared in order.
+    pub fn approx_eq(&self, other: &JsonNode, epsilon: f64) -> bool {
+        fn integer(node: &JsonNode) -> Option<i128> {
+            match node {
+                JsonNode::Integer(value) => Some(*value as i128),
+                JsonNode::UInteger(value) => Some(*value as i128),
+                _ => None,
+            }
+        }
+
+        match (self, other) {
+            (JsonNode::Object(a), JsonNode::Object(b)) => {
+                a.len() == b.len() && a.iter().all(|(key, a_value)| b.get(key).is_some_and(|b_value| a_value.approx_eq(b_value, epsilon)))
+            },
+            (JsonNode::Array(a), JsonNode::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.approx_eq(b, epsilon))
+            },
+            (a, b) if integer(a).is_some() && integer(b).is_some() => integer(a) == integer(b),
+            (a, b) => match (a.as_f64(), b.as_f64()) {
+                (Some(a), Some(b)) if a.is_nan() || b.is_nan() => a.is_nan() && b.is_nan(),
+                (Some(a), Some(b)) if a.is_infinite() || b.is_infinite() => a == b,
+                (Some(a), Some(b)) => (a - b).abs() <= epsilon,
+                _ => a == b,
+            },
+        }
+    }
+
+    /// Sorts the elements of the node in place if it is the `JsonNode::Array` discriminant. Any other node is left untouched.
+    /// 
+    /// The sort is stable and uses the order described by `JsonNode::total_cmp`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut array_node = JsonNode::parse(r#"["b", 2, null, 1.5, true, "a"]"#).unwrap();
+    /// array_node.sort_array();
+    /// 
+    /// assert_eq!(array_node.to_json_string(), r#"[null,true,1.5,2,"a","b"]"#);
+    /// ```
+    pub fn sort_array(&mut self) {
+        if let JsonNode::Array(array) = self {
+            array.sort();
+        }
+    }
+
+    /// Replaces every object key in the node tree with the result of `f`. String values are left untouched.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Creates the new key from the old one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"name": "jason", "children": [{"name": "jasmine"}]}"#).unwrap();
+    /// node.map_keys(|key| key.to_uppercase());
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"NAME":"jason","CHILDREN":[{"NAME":"jasmine"}]}"#);
+    /// ```
+    pub fn map_keys(&mut self, f: impl Fn(&str) -> String) {
+        map_keys_in(self, &f);
+    }
+
+    /// Replaces every string value in the node tree with the result of `f`. Object keys are left untouched.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Creates the new string from the old one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"name": " jason ", "tags": [" a", "b "]}"#).unwrap();
+    /// node.map_strings(|value| value.trim().to_owned());
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"name":"jason","tags":["a","b"]}"#);
+    /// ```
+    pub fn map_strings(&mut self, f: impl Fn(&str) -> String) {
+        map_strings_in(self, &f);
+    }
+
+    /// Calls `f` on every leaf of the node tree, meaning every value which isn't an object or an array, so it can be changed in place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Changes a leaf. It may replace the leaf with any node, including a container, which is not visited again.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"password": "hunter2", "tokens": ["abc", 1]}"#).unwrap();
+    /// 
+    /// node.map_leaves(|leaf| {
+    ///     if leaf.is_string() {
+    ///         *leaf = JsonNode::String("***".to_owned());
+    ///     }
+    /// });
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"password":"***","tokens":["***",1]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Leaves are visited depth first in the order they appear. If the node itself is a leaf, `f` is called on it.
+    pub fn map_leaves<F: FnMut(&mut JsonNode)>(&mut self, mut f: F) {
+        map_leaves_in(self, &mut f);
+    }
+
+    /// Checks whether `f` returns `true` for any leaf of the node tree.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Tests a leaf.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"name": "Jason", "spouse": null, "numbers": [1, 2]}"#).unwrap();
+    /// 
+    /// assert!(node.any_leaf(|leaf| leaf.is_null()));
+    /// assert!(!node.any_leaf(|leaf| leaf.is_float()));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Leaves are visited in the same order as `map_leaves`, stopping at the first one `f` returns `true` for.
+    /// Empty containers have no leaves, so `f` is never called for them.
+    pub fn any_leaf<F: Fn(&JsonNode) -> bool>(&self, f: F) -> bool {
+        any_leaf_in(self, &f)
+    }
+
+    /// Checks whether `f` returns `true` for every leaf of the node tree.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Tests a leaf.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"tags": ["a", "b"], "owner": {"name": "Jason"}}"#).unwrap();
+    /// 
+    /// assert!(node.all_leaves(|leaf| leaf.is_string()));
+    /// assert!(JsonNode::Array(Vec::new()).all_leaves(|leaf| leaf.is_string()));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Stops at the first leaf `f` returns `false` for. A tree without leaves, like an empty array, returns `true`.
+    pub fn all_leaves<F: Fn(&JsonNode) -> bool>(&self, f: F) -> bool {
+        !any_leaf_in(self, &|leaf: &JsonNode| !f(leaf))
+    }
+
+    /// Removes every object property whose value is `null`, throughout the node tree.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"name": "Jason", "spouse": null, "children": [{"age": null}, null]}"#).unwrap();
+    /// node.remove_nulls();
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"name":"Jason","children":[{},null]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// `null` elements of arrays are kept, since removing them would shift the index of every element after them.
+    /// Objects which become empty are kept as well.
+    pub fn remove_nulls(&mut self) {
+        match self {
+            JsonNode::Object(object) => {
+                object.retain(|_, value| !value.is_null());
+                object.iter_mut().for_each(|(_, value)| value.remove_nulls());
+            },
+            JsonNode::Array(array) => array.iter_mut().for_each(JsonNode::remove_nulls),
+            _ => (),
+        }
+    }
+
+    /// Removes every empty object and empty array nested in the node tree, including containers which only become empty because their own empty children were removed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"name": "Jason", "tags": [], "address": {"lines": [{}, []]}, "numbers": [1, []]}"#).unwrap();
+    /// node.remove_empty_containers();
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"name":"Jason","numbers":[1]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Children are cleaned up before their parent is checked, so a single pass reaches the same result as repeating it until nothing changes.
+    /// The node itself is never removed, so it is left as an empty object or array if everything inside it was empty.
+    /// Removing empty elements from an array shifts the index of every element after them.
+    pub fn remove_empty_containers(&mut self) {
+        let is_empty_container = |node: &JsonNode| match node {
+            JsonNode::Object(object) => object.is_empty(),
+            JsonNode::Array(array) => array.is_empty(),
+            _ => false,
+        };
+
+        match self {
+            JsonNode::Object(object) => {
+                object.iter_mut().for_each(|(_, value)| value.remove_empty_containers());
+                object.retain(|_, value| !is_empty_container(value));
+            },
+            JsonNode::Array(array) => {
+                array.iter_mut().for_each(JsonNode::remove_empty_containers);
+                array.retain(|element| !is_empty_container(element));
+            },
+            _ => (),
+        }
+    }
+
+    /// Removes unnecessary trailing zeros from every float in the node tree, so `1.2000` becomes `1.2` and `3.000` becomes `3.0`.
+    /// 
+    /// `JsonNode::Float` values are always written without trailing zeros already,
+    /// so this only changes numbers kept as text by `ParseOptions::preserve_number_text`.
+    /// The digits are trimmed as text, which keeps the exact value of numbers too precise for an `f64`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, ParseOptions};
+    /// 
+    /// let options = ParseOptions {
+    ///     preserve_number_text: true,
+    ///     ..ParseOptions::default()
+    /// };
+    /// 
+    /// let mut node = JsonNode::parse_with_options("[1.2000, 3.000, 1.50e3, 100]", &options).unwrap();
+    /// node.compact_floats();
+    /// 
+    /// assert_eq!(node.to_json_string(), "[1.2,3.0,1.5e3,100]");
+    /// ```
+    pub fn compact_floats(&mut self) {
+        match self {
+            JsonNode::Object(object) => object.iter_mut().for_each(|(_, value)| value.compact_floats()),
+            JsonNode::Array(array) => array.iter_mut().for_each(JsonNode::compact_floats),
+            JsonNode::RawNumber(text) => {
+                let exponent_start = text.find(['e', 'E']).unwrap_or(text.len());
+                let (mantissa, exponent) = text.split_at(exponent_start);
+
+                if let Some((integer, fraction)) = mantissa.split_once('.') {
+                    let fraction = fraction.trim_end_matches('0');
+                    let fraction = if fraction.is_empty() { "0" } else { fraction };
+
+                    *text = format!("{}.{}{}", integer, fraction, exponent);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    /// Convert the node tree to a JSON string.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// // Create a JsonNode tree.
+    /// let node_tree = JsonNode::Array(Vec::from([
+    ///     JsonNode::Integer(0),
+    ///     JsonNode::Float(0.5),
+    ///     JsonNode::Integer(1),
+    ///     JsonNode::Null,
+    ///     JsonNode::Boolean(false)
+    /// ]));
+    /// 
+    /// let json_string = node_tree.to_json_string();
+    /// 
+    /// assert_eq!(json_string, "[0,0.5,1,null,false]".to_owned());
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// This function does zero formatting. The entire JSON string is returned without any spaces or new-lines.
+    /// 
+    /// Floats are written with the fewest digits which parse back to the exact same `f64`.
+    /// Integral floats keep a `.0`, so `JsonNode::Float(2.0)` is written as `2.0` and parses back as a float rather than an integer.
+    /// Strings are escaped, so `"`, `\` and control characters are written as escape sequences.
+    /// 
+    /// `NaN` and infinite floats have no JSON representation and are written as `NaN`, `inf` or `-inf`, which other parsers reject.
+    /// Use `JsonNode::try_to_json_string` when the tree might contain them.
+    pub fn to_json_string(&self) -> String {
+        self.to_json_string_with_options(&SerializeOptions::default())
+    }
+
+    /// Convert the node tree to compact JSON like `JsonNode::to_json_string`, as UTF-8 bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"name": "Jasmine"}"#).unwrap();
+    /// 
+    /// assert_eq!(node.to_json_bytes(), br#"{"name":"Jasmine"}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// The bytes are serialized straight into the returned buffer, which avoids going through a `String` for sinks like sockets and hashers.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        JsonNodeSerializer::write_compact(&mut buffer, self, &SerializeOptions::default(), 0)
+            .expect("writing to a Vec<u8> never fails");
+
+        buffer
+    }
+
+    /// Convert the node tree to a compact JSON string like `JsonNode::to_json_string`, consuming the node.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let json = JsonNode::Array(vec![JsonNode::String("Jason".to_owned()), JsonNode::Integer(30)]).into_json_string();
+    /// 
+    /// assert_eq!(json, r#"["Jason",30]"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Every element of an array and every property of an object is dropped as soon as it has been written, so the tree and its JSON are never both fully in memory.
+    /// Strings are still copied into the output, since they have to be quoted and escaped.
+    pub fn into_json_string(self) -> String {
+        let mut buffer = Vec::new();
+
+        JsonNodeSerializer::write_compact_owned(&mut buffer, self, &SerializeOptions::default())
+            .expect("writing to a Vec<u8> never fails");
+
+        String::from_utf8(buffer).expect("serialized JSON is always valid UTF-8")
+    }
+
+    /// Converts an array node to JSON Lines, where every element is written as compact JSON on its own line.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"[{"id": 1}, [true], null]"#).unwrap();
+    /// 
+    /// assert_eq!(node.to_jsonl().unwrap(), "{\"id\":1}\n[true]\nnull\n");
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Every line ends with `\n`, including the last one, and an empty array gives an empty string.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::TypeMismatch` if the node isn't an array.
+    pub fn to_jsonl(&self) -> Result<String> {
+        let array = self.as_array().ok_or(JsonNodeError::TypeMismatch {
+            expected: "array",
+            found: self.type_name(),
+        })?;
+
+        Ok(array
+            .iter()
+            .map(|node| node.to_json_string() + "\n")
+            .collect())
+    }
+
+    /// Convert the node tree to a compact JSON string, formatting floats as the options describe.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `options` - Controls how floats and strings are written. The indentation and line endings are only used when pretty printing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{FloatFormat, JsonNode, SerializeOptions};
+    /// 
+    /// let node_tree = JsonNode::Array(vec![JsonNode::Float(0.1 + 0.2), JsonNode::Float(2.0)]);
+    /// 
+    /// let options = SerializeOptions {
+    ///     float_format: FloatFormat::Decimals(2),
+    ///     ..SerializeOptions::default()
+    /// };
+    /// 
+    /// assert_eq!(node_tree.to_json_string_with_options(&options), "[0.30,2.00]");
+    /// ```
+    pub fn to_json_string_with_options(&self, options: &SerializeOptions) -> String {
+        let mut buffer = Vec::new();
+
+        JsonNodeSerializer::write_compact(&mut buffer, self, options, 0)
+            .expect("writing to a Vec<u8> never fails");
+
+        String::from_utf8(buffer).expect("serialized JSON is always valid UTF-8")
+    }
+
+    /// Convert the node tree to a JSON string like `JsonNode::to_json_string`, writing the listed property names first in every object.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `order` - The property names to write first, in order. Names an object doesn't have are ignored.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"age": 30, "tags": [], "name": "Jason", "id": 1}"#).unwrap();
+    /// 
+    /// assert_eq!(node.to_json_string_with_key_order(&["id", "name"]), r#"{"id":1,"name":"Jason","age":30,"tags":[]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Properties which aren't listed follow the listed ones in insertion order.
+    /// The same order can be used for pretty printing with `SerializeOptions::key_order`.
+    pub fn to_json_string_with_key_order(&self, order: &[&str]) -> String {
+        let options = SerializeOptions {
+            key_order: order.iter().map(|key| key.to_string()).collect(),
+            ..SerializeOptions::default()
+        };
+
+        self.to_json_string_with_options(&options)
+    }
+
+    /// Convert the node tree to a JSON string like `JsonNode::to_json_string`, replacing objects and arrays nested deeper than `max_depth` with `"..."`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_depth` - How many levels of objects and arrays to write, where the root is level one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"name": "Jason", "children": [{"name": "Jasmine"}], "tags": []}"#).unwrap();
+    /// 
+    /// assert_eq!(node.to_json_string_truncated(2), r#"{"name":"Jason","children":["..."],"tags":[]}"#);
+    /// assert_eq!(node.to_json_string_truncated(1), r#"{"name":"Jason","children":"...","tags":"..."}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Strings, numbers, booleans and nulls are always written, however deep they are.
+    /// The placeholder is `SerializeOptions::truncation_placeholder`, so set `max_depth` and the placeholder there to write something like `{…}` instead.
+    pub fn to_json_string_truncated(&self, max_depth: usize) -> String {
+        let options = SerializeOptions {
+            max_depth: Some(max_depth),
+            ..SerializeOptions::default()
+        };
+
+        self.to_json_string_with_options(&options)
+    }
+
+    /// Convert the node tree to a JSON string like `JsonNode::to_json_string`, but fail rather than write invalid JSON.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonNodeError};
+    /// 
+    /// assert_eq!(JsonNode::Array(vec![JsonNode::Float(0.5)]).try_to_json_string(), Ok("[0.5]".to_owned()));
+    /// assert_eq!(JsonNode::Array(vec![JsonNode::Float(f64::NAN)]).try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("NaN".to_owned())));
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `JsonNodeError::NonFiniteFloat` holding the first `NaN` or infinite float found in the tree.
+    pub fn try_to_json_string(&self) -> Result<String> {
+        match first_non_finite_float_in(self) {
+            Some(value) => Err(JsonNodeError::NonFiniteFloat(value.to_string())),
+            None => Ok(self.to_json_string()),
+        }
+    }
+
+    /// Convert the node tree to an indented JSON string using the default `SerializeOptions`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node_tree = JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Null]);
+    /// 
+    /// assert_eq!(node_tree.to_json_string_pretty(), "[\n    1,\n    null\n]");
+    /// ```
+    pub fn to_json_string_pretty(&self) -> String {
+        let mut buffer = Vec::new();
+
+        self.write_json_pretty_with(&mut buffer, &SerializeOptions::default())
+            .expect("writing to a Vec<u8> never fails");
+
+        String::from_utf8(buffer).expect("serialized JSON is always valid UTF-8")
+    }
+
+    /// Wraps the node tree so it is shown as indented JSON, which is easier to read than the derived `Debug` output for large trees.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"numbers": [1, 2]}"#).unwrap();
+    /// 
+    /// assert_eq!(format!("{}", node.debug_pretty()), "{\n    \"numbers\": [\n        1,\n        2\n    ]\n}");
+    /// assert_eq!(format!("{:?}", node.debug_pretty()), format!("{}", node.debug_pretty()));
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// The wrapper formats the same way with `{}` and `{:?}`, so it can be passed to `dbg!` and assertion messages.
+    /// The derived `Debug` implementation of `JsonNode` is left as it is.
+    pub fn debug_pretty(&self) -> PrettyDebug<'_> {
+        PrettyDebug(self)
+    }
+
+    /// Writes the node tree as indented JSON directly into a writer.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `writer` - The writer the JSON is written to.
+    /// * `options` - Controls the indentation, line endings and how floats and strings are written.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap, LineEnding, SerializeOptions};
+    /// 
+    /// let node_tree = JsonNode::Object(JsonPropertyMap::from([
+    ///     ("numbers".to_owned(), JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Integer(2)])),
+    /// ]));
+    /// 
+    /// let options = SerializeOptions {
+    ///     indent: "  ".to_owned(),
+    ///     line_ending: LineEnding::Lf,
+    ///     ..SerializeOptions::default()
+    /// };
+    /// 
+    /// let mut buffer = Vec::new();
+    /// node_tree.write_json_pretty_with(&mut buffer, &options).unwrap();
+    /// 
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "{\n  \"numbers\": [\n    1,\n    2\n  ]\n}");
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer.
+    pub fn write_json_pretty_with<W: Write>(&self, writer: &mut W, options: &SerializeOptions) -> io::Result<()> {
+        JsonNodeSerializer::write_pretty(writer, self, options, 0)
+    }
+}
+
+/// Gets the child of `node` named by one path segment, failing if the segment is the wrong kind for the node.
+fn child<'a>(node: &'a JsonNode, segment: &str) -> Result<Option<&'a JsonNode>> {
+    let index = segment.parse::<usize>().ok();
+
+    match (node, index) {
+        (JsonNode::Object(object), index) => match object.get(segment) {
+            None if index.is_some() => Err(JsonNodeError::TypeMismatch { expected: "array", found: node.type_name() }),
+            found => Ok(found),
+        },
+        (JsonNode::Array(array), Some(index)) => Ok(array.get(index)),
+        (_, Some(_)) => Err(JsonNodeError::TypeMismatch { expected: "array", found: node.type_name() }),
+        (_, None) => Err(JsonNodeError::TypeMismatch { expected: "object", found: node.type_name() }),
+    }
+}
+
+/// Checks if any of the children is a non-empty object or array.
+fn has_nested_children<'a>(mut children: impl Iterator<Item = &'a JsonNode>) -> bool {
+    children.any(|child| match child {
+        JsonNode::Object(object) => !object.is_empty(),
+        JsonNode::Array(array) => !array.is_empty(),
+        _ => false,
+    })
+}
+
+fn map_keys_in(node: &mut JsonNode, f: &impl Fn(&str) -> String) {
+    match node {
+        JsonNode::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                *key = f(key);
+                map_keys_in(value, f);
+            }
+        },
+        JsonNode::Array(array) => array.iter_mut().for_each(|element| map_keys_in(element, f)),
+        _ => (),
+    }
+}
+
+fn map_strings_in(node: &mut JsonNode, f: &impl Fn(&str) -> String) {
+    match node {
+        JsonNode::Object(object) => object.iter_mut().for_each(|(_, value)| map_strings_in(value, f)),
+        JsonNode::Array(array) => array.iter_mut().for_each(|element| map_strings_in(element, f)),
+        JsonNode::String(value) => *value = f(value),
+        _ => (),
+    }
+}
+
+fn count_types_in(node: &JsonNode, histogram: &mut HashMap<&'static str, usize>) {
+    *histogram.entry(node.type_name()).or_default() += 1;
+
+    match node {
+        JsonNode::Object(object) => object.iter().for_each(|(_, value)| count_types_in(value, histogram)),
+        JsonNode::Array(array) => array.iter().for_each(|element| count_types_in(element, histogram)),
+        _ => (),
+    }
+}
+
+fn map_leaves_in(node: &mut JsonNode, f: &mut impl FnMut(&mut JsonNode)) {
+    match node {
+        JsonNode::Object(object) => object.iter_mut().for_each(|(_, value)| map_leaves_in(value, f)),
+        JsonNode::Array(array) => array.iter_mut().for_each(|element| map_leaves_in(element, f)),
+        _ => f(node),
+    }
+}
+
+/// Walks the tree with an explicit stack like the serializer does, so deeply nested trees can't overflow the call stack.
+fn first_non_finite_float_in(node: &JsonNode) -> Option<f64> {
+    let mut stack = vec![node];
+
+    while let Some(node) = stack.pop() {
+        match node {
+            // Children are pushed in reverse so the first float in document order is found first.
+            JsonNode::Object(object) => stack.extend(object.iter().rev().map(|(_, value)| value)),
+            JsonNode::Array(array) => stack.extend(array.iter().rev()),
+            JsonNode::Float(value) if !value.is_finite() => return Some(*value),
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn any_leaf_in(node: &JsonNode, f: &impl Fn(&JsonNode) -> bool) -> bool {
+    match node {
+        JsonNode::Object(object) => object.iter().any(|(_, value)| any_leaf_in(value, f)),
+        JsonNode::Array(array) => array.iter().any(|element| any_leaf_in(element, f)),
+        _ => f(node),
+    }
+}
+
+/// Adds every leaf and empty container below `node` to `flat`, keyed by its path from the root.
+fn flatten_into(node: &JsonNode, path: String, flat: &mut JsonPropertyMap) {
+    match node {
+        JsonNode::Object(object) if !object.is_empty() => {
+            for (property_name, child) in object.iter() {
+                let child_path = if path.is_empty() {
+                    property_name.clone()
+                } else {
+                    format!("{}.{}", path, property_name)
+                };
+
+                flatten_into(child, child_path, flat);
+            }
+        },
+        JsonNode::Array(array) if !array.is_empty() => {
+            for (index, child) in array.iter().enumerate() {
+                flatten_into(child, format!("{}[{}]", path, index), flat);
+            }
+        },
+        _ => flat.add(&path, node.clone()),
+    }
+}
+
+/// How many `null` gaps `JsonNode::unflatten` allows beyond its number of entries, bounding the arrays it allocates.
+const MAX_UNFLATTEN_INDEX_GAP: usize = 1024;
+
+/// One step of a path used by `JsonNode::unflatten`.
+enum PathSegment<'a> {
+    Property(&'a str),
+    Index(usize),
+}
+
+/// Splits a flattened path like `children[0].name` into its segments, or `None` if an index is malformed or larger than `max_index`.
+fn parse_flat_path(path: &str, max_index: usize) -> Option<Vec<PathSegment<'_>>> {
+    let mut segments = Vec::new();
+
+    if path.is_empty() {
+        return Some(segments);
+    }
+
+    for (position, part) in path.split('.').enumerate() {
+        let (property_name, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+
+        if position > 0 || !property_name.is_empty() || indices.is_empty() {
+            segments.push(PathSegment::Property(property_name));
+        }
+
+        while !indices.is_empty() {
+            let end = indices.find(']')?;
+            let index = indices.get(1..end)?.parse().ok().filter(|index| *index <= max_index)?;
+            segments.push(PathSegment::Index(index));
+            indices = &indices[end + 1..];
+
+            if !indices.is_empty() && !indices.starts_with('[') {
+                return None;
+            }
+        }
+    }
+
+    Some(segments)
+}
+
+/// A partially rebuilt node tree, which tells the slots nothing has been written to apart from written `null` values.
+enum UnflattenSlot {
+    Vacant,
+    Leaf(JsonNode),
+    Object(Vec<(String, UnflattenSlot)>),
+    Array(Vec<UnflattenSlot>),
+}
+
+impl UnflattenSlot {
+    /// Writes `node` at the end of `segments`, returning `false` if that conflicts with what is already in the tree.
+    fn insert(&mut self, segments: &[PathSegment], node: &JsonNode) -> bool {
+        let Some((segment, rest)) = segments.split_first() else {
+            return self.set(node);
+        };
+
+        if let UnflattenSlot::Vacant = self {
+            *self = match segment {
+                PathSegment::Property(_) => UnflattenSlot::Object(Vec::new()),
+                PathSegment::Index(_) => UnflattenSlot::Array(Vec::new()),
+            };
+        }
+
+        match (self, segment) {
+            (UnflattenSlot::Object(properties), PathSegment::Property(property_name)) => {
+                let position = match properties.iter().position(|(name, _)| name == property_name) {
+                    Some(position) => position,
+                    None => {
+                        properties.push((property_name.to_string(), UnflattenSlot::Vacant));
+                        properties.len() - 1
+                    },
+                };
+
+                properties[position].1.insert(rest, node)
+            },
+            (UnflattenSlot::Array(elements), PathSegment::Index(index)) => {
+                if elements.len() <= *index {
+                    elements.resize_with(index + 1, || UnflattenSlot::Vacant);
+                }
+
+                elements[*index].insert(rest, node)
+            },
+            _ => false,
+        }
+    }
+
+    /// Writes `node` into this slot. Empty objects and arrays merge with a slot of the same kind.
+    fn set(&mut self, node: &JsonNode) -> bool {
+        match (&*self, node) {
+            (UnflattenSlot::Vacant, JsonNode::Object(object)) if object.is_empty() => *self = UnflattenSlot::Object(Vec::new()),
+            (UnflattenSlot::Vacant, JsonNode::Array(array)) if array.is_empty() => *self = UnflattenSlot::Array(Vec::new()),
+            (UnflattenSlot::Vacant, _) => *self = UnflattenSlot::Leaf(node.clone()),
+            (UnflattenSlot::Object(_), JsonNode::Object(object)) if object.is_empty() => (),
+            (UnflattenSlot::Array(_), JsonNode::Array(array)) if array.is_empty() => (),
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn into_node(self) -> JsonNode {
+        match self {
+            UnflattenSlot::Vacant => JsonNode::Null,
+            UnflattenSlot::Leaf(node) => node,
+            UnflattenSlot::Object(properties) => JsonNode::Object(properties
+                .into_iter()
+                .map(|(property_name, slot)| (property_name, slot.into_node()))
+                .collect()),
+            UnflattenSlot::Array(elements) => JsonNode::Array(elements
+                .into_iter()
+                .map(UnflattenSlot::into_node)
+                .collect()),
+        }
+    }
+}
+
+/// Maps `-0.0` to `0.0` and every `NaN` to the same `NaN`, so floats which should be equal also have the same bits.
+fn normalize_float(value: f64) -> f64 {
+    if value == 0.0 {
+        0.0
+    } else if value.is_nan() {
+        f64::NAN
+    } else {
+        value
+    }
+}
+
+/// Compares two number nodes by value, then by discriminant and finally by the text of raw numbers.
+fn compare_numbers(a: &JsonNode, b: &JsonNode) -> Ordering {
+    fn discriminant_order(node: &JsonNode) -> u8 {
+        match node {
+            JsonNode::Integer(_) => 0,
+            JsonNode::UInteger(_) => 1,
+            JsonNode::Float(_) => 2,
+            _ => 3,
+        }
+    }
+
+    fn integer_value(node: &JsonNode) -> Option<i128> {
+        match node {
+            JsonNode::Integer(value) => Some(i128::from(*value)),
+            JsonNode::UInteger(value) => Some(i128::from(*value)),
+            _ => None,
+        }
+    }
+
+    let float_value = |node: &JsonNode| normalize_float(node.as_f64().unwrap_or(f64::NAN));
+
+    let by_value = match (integer_value(a), integer_value(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(a), None) => compare_integer_to_float(a, float_value(b)),
+        (None, Some(b)) => compare_integer_to_float(b, float_value(a)).reverse(),
+        (None, None) => float_value(a).total_cmp(&float_value(b)),
+    };
+
+    by_value
+        .then(discriminant_order(a).cmp(&discriminant_order(b)))
+        .then_with(|| match (a, b) {
+            (JsonNode::RawNumber(a), JsonNode::RawNumber(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        })
+}
+
+/// Compares an integer and a float by their exact values, without rounding the integer to a float first.
+fn compare_integer_to_float(integer: i128, float: f64) -> Ordering {
+    // 2^127 is exactly representable as a float and is the first value outside the range of `i128`.
+    const TWO_POW_127: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+    if float.is_nan() || float >= TWO_POW_127 {
+        return Ordering::Less;
+    }
+
+    if float < -TWO_POW_127 {
+        return Ordering::Greater;
+    }
+
+    let whole = float.trunc();
+
+    integer.cmp(&(whole as i128)).then_with(|| 0.0_f64.total_cmp(&normalize_float(float - whole)))
+}
+
+/// Nodes are equal when they are the same discriminant with equal contents.
+/// 
+/// Floats are compared by their bits after mapping `-0.0` to `0.0` and every `NaN` to the same `NaN`.
+/// Raw numbers are compared by their text.
+/// Unlike `f64`, this makes `NaN` equal to itself, which means `JsonNode` can implement `Eq` and `Hash` and be used as a key in a `HashMap` or `HashSet`.
+/// Integers, unsigned integers, floats and raw numbers are never equal to each other, even when they have the same value.
+impl PartialEq for JsonNode {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (JsonNode::Object(a), JsonNode::Object(b)) => a == b,
+            (JsonNode::Array(a), JsonNode::Array(b)) => a == b,
+            (JsonNode::String(a), JsonNode::String(b)) => a == b,
+            (JsonNode::Integer(a), JsonNode::Integer(b)) => a == b,
+            (JsonNode::UInteger(a), JsonNode::UInteger(b)) => a == b,
+            (JsonNode::Float(a), JsonNode::Float(b)) => normalize_float(*a).to_bits() == normalize_float(*b).to_bits(),
+            (JsonNode::Boolean(a), JsonNode::Boolean(b)) => a == b,
+            (JsonNode::Null, JsonNode::Null) => true,
+            (JsonNode::RawNumber(a), JsonNode::RawNumber(b)) => a == b,
+            #[cfg(feature = "undefined")]
+            (JsonNode::Undefined, JsonNode::Undefined) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for JsonNode {}
+
+/// Dismantles the tree with an explicit stack, so dropping a deeply nested tree can't overflow the call stack.
+/// 
+/// Since `JsonNode` implements `Drop`, the contents of an owned node can't be moved out by destructuring it, like `let JsonNode::String(text) = node`.
+/// That is a compile error (E0509), so match on `ref mut` bindings and move the contents out with `std::mem::take` instead.
+impl Drop for JsonNode {
+    fn drop(&mut self) {
+        // Children without children of their own are dropped without recursing any further, so only nested trees need the stack.
+        let mut stack = match self {
+            JsonNode::Object(object) if has_nested_children(object.iter().map(|(_, value)| value)) => object.drain().map(|(_, value)| value).collect(),
+            JsonNode::Array(array) if has_nested_children(array.iter()) => mem::take(array),
+            _ => return,
+        };
+
+        while let Some(mut node) = stack.pop() {
+            match &mut node {
+                JsonNode::Object(object) => stack.extend(object.drain().map(|(_, value)| value)),
+                JsonNode::Array(array) => stack.append(array),
+                _ => (),
+            }
+        }
+    }
+}
+
+impl Hash for JsonNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            JsonNode::Object(object) => object.hash(state),
+            JsonNode::Array(array) => array.hash(state),
+            JsonNode::String(value) => value.hash(state),
+            JsonNode::Integer(value) => value.hash(state),
+            JsonNode::UInteger(value) => value.hash(state),
+            JsonNode::Float(value) => normalize_float(*value).to_bits().hash(state),
+            JsonNode::Boolean(value) => value.hash(state),
+            JsonNode::Null => (),
+            JsonNode::RawNumber(text) => text.hash(state),
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => (),
+        }
+    }
+}
+
+impl PartialOrd for JsonNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders nodes as described by `JsonNode::total_cmp`.
+impl Ord for JsonNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+/// Collects nodes into a `JsonNode::Array`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::JsonNode;
+/// 
+/// let node = [JsonNode::Null, JsonNode::Boolean(true)].into_iter().collect::<JsonNode>();
+/// 
+/// assert_eq!(node, JsonNode::Array(vec![JsonNode::Null, JsonNode::Boolean(true)]));
+/// ```
+impl FromIterator<JsonNode> for JsonNode {
+    fn from_iter<T: IntoIterator<Item = JsonNode>>(iter: T) -> Self {
+        JsonNode::Array(iter.into_iter().collect())
+    }
+}
+
+/// Converts values with `ToJsonNode` and collects them into a `JsonNode::Array`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::JsonNode;
+/// 
+/// let node = (1..=3).collect::<JsonNode>();
+/// 
+/// assert_eq!(node.to_json_string(), "[1,2,3]");
+/// ```
+impl<V: ToJsonNode> FromIterator<V> for JsonNode {
+    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
+        JsonNode::Array(iter.into_iter().map(|value| value.to_json_node()).collect())
+    }
+}
+
+/// Appends nodes to the end of a `JsonNode::Array`.
+/// 
+/// # Panics
+/// 
+/// Panics if the node isn't an array. Use `JsonNode::push` to get an error instead.
+impl Extend<JsonNode> for JsonNode {
+    fn extend<T: IntoIterator<Item = JsonNode>>(&mut self, iter: T) {
+        match self {
+            JsonNode::Array(array) => array.extend(iter),
+            _ => panic!("cannot extend a JsonNode::{} like an array", self.type_name()),
+        }
+    }
+}
+
+/// Converts values with `ToJsonNode` and appends them to the end of a `JsonNode::Array`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::JsonNode;
+/// 
+/// let mut node = JsonNode::parse(r#"["a"]"#).unwrap();
+/// node.extend(["b", "c"]);
+/// 
+/// assert_eq!(node.to_json_string(), r#"["a","b","c"]"#);
+/// ```
+/// 
+/// # Panics
+/// 
+/// Panics if the node isn't an array. Use `JsonNode::push` to get an error instead.
+impl<V: ToJsonNode> Extend<V> for JsonNode {
+    fn extend<T: IntoIterator<Item = V>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|value| value.to_json_node()));
+    }
+}
+
+impl<'a> IntoIterator for &'a JsonNode {
+    type Item = &'a JsonNode;
+    type IntoIter = Iter<'a>;
+
+    /// Turns the node tree into an iterator which iterates over evey `JsonNode` in the tree in a depth first manner.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    ///     
+    /// let node_tree = JsonNode::Array(Vec::from([
+    ///     JsonNode::Array(Vec::from([                     // First element is an array with the value `1` inside.
+    ///         JsonNode::Integer(1),
+    ///     ])),
+    ///     JsonNode::Integer(2),         // Second element is the value `2`.
+    ///     JsonNode::Array(Vec::from([
+    ///         JsonNode::Integer(3)      // Third element is an array with the value `3` inside.
+    ///     ]))
+    /// ]));
+    /// 
+    /// let sequence = node_tree.into_iter().collect::<Vec<&JsonNode>>();
+    /// 
+    /// let expected = [
+    ///     JsonNode::Integer(1),
+    ///     JsonNode::Integer(2),
+    ///     JsonNode::Integer(3)
+    /// ];
+    /// 
+    /// assert_eq!(sequence, expected.iter().collect::<Vec<&JsonNode>>());
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            node: Some(self),
+            array_index: None,
+            object_index: None,
+            child: None,
+        }
+    }
+}
+
+pub struct Iter<'a> {
+    node: Option<&'a JsonNode>,
+    array_index: Option<usize>,
+    object_index: Option<usize>,
+    child: Option<Box<Iter<'a>>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a JsonNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = &mut self.child {
+            if let Some(node) = iter.next() {
+                return Some(node);
+            }
+
+            self.child = None;
+        }
+
+        // Termination point for iteration. If the iterator has recursed, this allows the parent iterator to continue.
+        let node = self.node?;
+
+        match node {
+            JsonNode::Array(nodes) => {
+                match self.array_index {
+                    Some(mut index) => {
+                        index += 1;
+                        self.array_index = Some(index);
+                        self.child = Some(Box::new(nodes[index].into_iter()));
+                        let next = self.next();
+
+                        if index == nodes.len() - 1 {
+                            self.array_index = None;
+                            self.node = None;
+                        }
+
+                        next
+                    },
+                    None => {
+                        self.array_index = Some(0);
+                        self.child = Some(Box::new(nodes[0].into_iter()));
+                        let next = self.next();
+
+                        if nodes.len() == 1 {
+                            self.array_index = None;
+                            self.node = None;
+                        }
+
+                        next
+                    },
+                }
+            },
+            JsonNode::Object(properties) => {
+                match self.object_index {
+                    Some(mut index) => {
+                        index += 1;
+                        self.object_index = Some(index);
+                        self.child = Some(Box::new(properties[index].1.into_iter()));
+                        let next = self.next();
+
+                        if index == properties.len() - 1 {
+                            self.object_index = None;
+                            self.node = None;
+                        }
+
+                        next
+                    },
+                    None => {
+                        self.object_index = Some(0);
+                        self.child = Some(Box::new(properties[0].1.into_iter()));
+                        let next = self.next();
+
+                        if properties.len() == 1 {
+                            self.object_index = None;
+                        }
+
+                        next
+                    },
+                }
+            },
+            _ => {
+                self.node = None;
+                Some(node)
+            },
+        }
+    }
+}
+
+/// Formats the node as JSON.
+/// 
+/// The default format is the same compact JSON as `JsonNode::to_json_string` and the alternate format (`{:#}`) is the same indented JSON as `JsonNode::to_json_string_pretty`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{JsonNode, JsonPropertyMap};
+/// 
+/// let node = JsonNode::Object(JsonPropertyMap::from([
+///     ("a".to_owned(), JsonNode::String("b".to_owned())),
+/// ]));
+/// 
+/// assert_eq!(format!("{}", node), r#"{"a":"b"}"#);
+/// assert_eq!(format!("{:#}", node), "{\n    \"a\": \"b\"\n}");
+/// ```
+impl Display for JsonNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_json_string_pretty())
+        } else {
+            write!(f, "{}", self.to_json_string())
+        }
+    }
+}
+
+/// Shows a node tree as indented JSON for both `Display` and `Debug`. Created by `JsonNode::debug_pretty`.
+pub struct PrettyDebug<'a>(&'a JsonNode);
+
+impl Display for PrettyDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.to_json_string_pretty())
+    }
+}
+
+impl std::fmt::Debug for PrettyDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::JsonNode;
+
+    const SAMPLE_JSON: &str = r#"
+    {
+        "name": "Jason",
+        "age": 30,
+        "isMale": true,
+        "height": 1.8,
+        "numbers": [1, 2, 3, 4, 5],
+        "children": [
+            {
+                "name": "Jason Jr.",
+                "age": 5,
+                "isMale": true,
+                "height": 1.2
+            },
+            {
+                "name": "Jasmine",
+                "age": 3,
+                "isMale": false,
+                "height": 1.1
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn iterate_works() {
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "isMale": true,
+            "height": 1.8,
+            "numbers": [1, 2, 3, 4, 5],
+            "children": [
+                {
+                    "name": "Jason Jr.",
+                    "age": 5,
+                    "isMale": true,
+                    "height": 1.2
+                },
+                {
+                    "name": "Jasmine",
+                    "age": 3,
+                    "isMale": false,
+                    "height": 1.1
+                }
+            ]
+        }"#;
+
+        let node = JsonNode::parse(json).unwrap();
+        for e in node.into_iter() {
+            println!("{:?}", e)
+        }
+    }
+
+    #[test]
+    fn remove_property_from_node() {
+        use crate::{JsonNodeError, JsonPropertyMap};
+
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "isMale": true
+        }"#;
+
+        let mut node = JsonNode::parse(json).unwrap();
+
+        assert_eq!(node.remove("age"), Ok(JsonNode::Integer(30)));
+        assert_eq!(node.remove("age"), Err(JsonNodeError::KeyNotFound("age".to_owned())));
+        assert_eq!(node, JsonNode::Object(JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("isMale".to_owned(), JsonNode::Boolean(true)),
+        ])));
+
+        let mut array = JsonNode::Array(Vec::new());
+        assert_eq!(array.remove("age"), Err(JsonNodeError::TypeMismatch { expected: "object", found: "array" }));
+    }
+
+    #[test]
+    fn push_and_insert_at_keep_order() {
+        use crate::JsonNodeError;
+
+        let mut node = JsonNode::Array(Vec::new());
+        node.push(JsonNode::Integer(2)).unwrap();
+        node.push(JsonNode::Integer(4)).unwrap();
+        node.insert_at(0, JsonNode::Integer(1)).unwrap();
+        node.insert_at(2, JsonNode::Integer(3)).unwrap();
+        node.as_array_mut().unwrap().push(JsonNode::Integer(5));
+        node.push(JsonNode::Integer(6)).unwrap();
+
+        let values = node.as_array()
+            .unwrap()
+            .iter()
+            .map(|element| *element.as_integer().unwrap())
+            .collect::<Vec<i64>>();
+
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(node.as_array_mut().unwrap().len(), 6);
+
+        assert_eq!(
+            JsonNode::Null.push(JsonNode::Null),
+            Err(JsonNodeError::TypeMismatch { expected: "array", found: "null" })
+        );
+    }
+
+    #[test]
+    fn contains_key_and_index() {
+        let node = JsonNode::parse(r#"{ "name": "Jason", "numbers": [1, 2, 3] }"#).unwrap();
+
+        assert!(node.contains_key("name"));
+        assert!(node.contains_key("numbers"));
+        assert!(!node.contains_key("age"));
+        assert!(!node.contains_index(0));
+
+        let numbers = node.as_object().unwrap().get("numbers").unwrap();
+
+        assert!(numbers.contains_index(0));
+        assert!(numbers.contains_index(2));
+        assert!(!numbers.contains_index(3));
+        assert!(!numbers.contains_key("name"));
+    }
+
+    #[test]
+    fn get_path_as_typed_values() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(node.get_path_as::<i64>("children.0.age"), Ok(5));
+        assert_eq!(node.get_path_as::<String>("children.1.name"), Ok("Jasmine".to_owned()));
+        assert_eq!(node.get_path_as::<f64>("numbers.4"), Ok(5.0));
+
+        assert_eq!(
+            node.get_path_as::<i64>("children.0.name"),
+            Err(JsonNodeError::TypeMismatch { expected: "integer", found: "string" })
+        );
+        assert_eq!(
+            node.get_path_as::<i64>("children.2.age"),
+            Err(JsonNodeError::PathNotFound("children.2.age".to_owned()))
+        );
+    }
+
+    #[test]
+    fn pointer_rejects_wrong_segment_kinds() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(node.pointer("/children/1/name"), Ok(&JsonNode::String("Jasmine".to_owned())));
+        assert_eq!(node.pointer(""), Ok(&node));
+        assert_eq!(node.pointer("/0"), Err(JsonNodeError::TypeMismatch { expected: "array", found: "object" }));
+        assert_eq!(node.pointer("/numbers/first"), Err(JsonNodeError::TypeMismatch { expected: "object", found: "array" }));
+        assert_eq!(node.pointer("/name/0"), Err(JsonNodeError::TypeMismatch { expected: "array", found: "string" }));
+        assert_eq!(node.pointer("/numbers/5"), Err(JsonNodeError::PathNotFound("/numbers/5".to_owned())));
+        assert_eq!(node.pointer("numbers"), Err(JsonNodeError::PathNotFound("numbers".to_owned())));
+        assert_eq!(node.get_path_as::<i64>("0.age"), Err(JsonNodeError::TypeMismatch { expected: "array", found: "object" }));
+
+        let numeric_keys = JsonNode::parse(r#"{"0": true}"#).unwrap();
+        assert_eq!(numeric_keys.pointer("/0"), Ok(&JsonNode::Boolean(true)));
+    }
+
+    #[test]
+    fn flatten_sample() {
+        let flat = JsonNode::parse(SAMPLE_JSON).unwrap().flatten();
+
+        assert_eq!(flat.get("name"), Some(&JsonNode::String("Jason".to_owned())));
+        assert_eq!(flat.get("numbers[2]"), Some(&JsonNode::Integer(3)));
+        assert_eq!(flat.get("children[0].name"), Some(&JsonNode::String("Jason Jr.".to_owned())));
+        assert_eq!(flat.get("children[1].isMale"), Some(&JsonNode::Boolean(false)));
+        assert_eq!(flat.get("children"), None);
+        assert_eq!(flat.len(), 17);
+
+        assert_eq!(JsonNode::Integer(1).flatten().get(""), Some(&JsonNode::Integer(1)));
+    }
+
+    #[test]
+    fn unflatten_round_trip() {
+        use crate::JsonPropertyMap;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        assert_eq!(JsonNode::unflatten(&node.flatten()), Ok(node));
+
+        let nested = JsonNode::parse(r#"[[1, []], {"a": {}, "b": [null]}]"#).unwrap();
+        assert_eq!(JsonNode::unflatten(&nested.flatten()), Ok(nested));
+
+        let flat = JsonPropertyMap::from([("list[2]".to_owned(), JsonNode::Boolean(true))]);
+        assert_eq!(JsonNode::unflatten(&flat).unwrap().to_json_string(), r#"{"list":[null,null,true]}"#);
+    }
+
+    #[test]
+    fn unflatten_errors() {
+        use crate::{JsonNodeError, JsonPropertyMap};
+
+        let flat = JsonPropertyMap::from([
+            ("a".to_owned(), JsonNode::Integer(1)),
+            ("a.b".to_owned(), JsonNode::Integer(2)),
+        ]);
+        assert_eq!(JsonNode::unflatten(&flat), Err(JsonNodeError::ConflictingPath("a.b".to_owned())));
+
+        let flat = JsonPropertyMap::from([
+            ("a[0]".to_owned(), JsonNode::Integer(1)),
+            ("a.b".to_owned(), JsonNode::Integer(2)),
+        ]);
+        assert_eq!(JsonNode::unflatten(&flat), Err(JsonNodeError::ConflictingPath("a.b".to_owned())));
+
+        let flat = JsonPropertyMap::from([("a[x]".to_owned(), JsonNode::Integer(1))]);
+        assert_eq!(JsonNode::unflatten(&flat), Err(JsonNodeError::InvalidKey("a[x]".to_owned())));
+    }
+
+    #[test]
+    fn unflatten_rejects_oversized_indices() {
+        use crate::{JsonNodeError, JsonPropertyMap};
+
+        for key in ["a[18446744073709551615]", "a[4000000000]", "a[0][2000]"] {
+            let flat = JsonPropertyMap::from([(key.to_owned(), JsonNode::Integer(1))]);
+            assert_eq!(JsonNode::unflatten(&flat), Err(JsonNodeError::InvalidKey(key.to_owned())));
+        }
+
+        let flat = JsonPropertyMap::from([
+            ("a[0]".to_owned(), JsonNode::Integer(1)),
+            ("a[2]".to_owned(), JsonNode::Integer(3)),
+        ]);
+        assert_eq!(JsonNode::unflatten(&flat).unwrap().to_json_string(), r#"{"a":[1,null,3]}"#);
+    }
+
+    #[test]
+    fn array_element_types_and_homogeneity() {
+        let node = JsonNode::parse(r#"{ "numbers": [1, 2, 3, 4, 5], "mixed": [1, "two", 3.0, null] }"#).unwrap();
+        let object = node.as_object().unwrap();
+
+        let numbers = object.get("numbers").unwrap();
+        assert_eq!(numbers.array_element_types(), Some(vec!["integer"; 5]));
+        assert!(numbers.is_homogeneous_array());
+
+        let mixed = object.get("mixed").unwrap();
+        assert_eq!(mixed.array_element_types(), Some(vec!["integer", "string", "float", "null"]));
+        assert!(!mixed.is_homogeneous_array());
+
+        assert_eq!(node.array_element_types(), None);
+        assert!(!node.is_homogeneous_array());
+    }
+
+    #[test]
+    fn entries_as_array_keeps_order() {
+        use crate::JsonPropertyMap;
+
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "numbers": [1, 2, 3, 4, 5]
+        }"#;
+
+        let entries = JsonNode::parse(json).unwrap().entries_as_array().unwrap();
+        let entries = entries.as_array().unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0], JsonNode::Object(JsonPropertyMap::from([
+            ("key".to_owned(), JsonNode::String("name".to_owned())),
+            ("value".to_owned(), JsonNode::String("Jason".to_owned())),
+        ])));
+        assert_eq!(entries[2].as_object().unwrap().get("key"), Some(&JsonNode::String("numbers".to_owned())));
+    }
+
+    #[test]
+    fn object_from_entries_round_trip() {
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "numbers": [1, 2, 3, 4, 5],
+            "spouse": null
+        }"#;
+
+        let node = JsonNode::parse(json).unwrap();
+        let entries = node.entries_as_array().unwrap();
+
+        assert_eq!(JsonNode::object_from_entries(&entries), Ok(node));
+    }
+
+    #[test]
+    fn object_from_entries_errors() {
+        use crate::JsonNodeError;
+
+        let duplicate = JsonNode::parse(r#"[{"key": "a", "value": 1}, {"key": "a", "value": 2}]"#).unwrap();
+        assert_eq!(JsonNode::object_from_entries(&duplicate), Err(JsonNodeError::MultiplePropertiesWithSameKey("a".to_owned())));
+
+        let missing_key = JsonNode::parse(r#"[{"value": 1}]"#).unwrap();
+        assert_eq!(JsonNode::object_from_entries(&missing_key), Err(JsonNodeError::KeyNotFound("key".to_owned())));
+
+        let missing_value = JsonNode::parse(r#"[{"key": "a"}]"#).unwrap();
+        assert_eq!(JsonNode::object_from_entries(&missing_value), Err(JsonNodeError::KeyNotFound("value".to_owned())));
+
+        let not_array = JsonNode::Null;
+        assert_eq!(JsonNode::object_from_entries(&not_array), Err(JsonNodeError::TypeMismatch { expected: "array", found: "null" }));
+    }
+
+    #[test]
+    fn eq_ignoring_timestamps() {
+        let a = JsonNode::parse(r#"
+        {
+            "name": "Jason",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "children": [
+                { "name": "Jasmine", "timestamp": 1 }
+            ]
+        }"#).unwrap();
+
+        let b = JsonNode::parse(r#"
+        {
+            "name": "Jason",
+            "timestamp": "2025-06-30T12:00:00Z",
+            "children": [
+                { "timestamp": 2, "name": "Jasmine" }
+            ]
+        }"#).unwrap();
+
+        let c = JsonNode::parse(r#"
+        {
+            "name": "Jasmine",
+            "children": [
+                { "name": "Jasmine" }
+            ]
+        }"#).unwrap();
+
+        assert_ne!(a, b);
+        assert!(a.eq_ignoring(&b, &["timestamp"]));
+        assert!(!a.eq_ignoring(&c, &["timestamp"]));
+    }
+
+    #[test]
+    fn sort_mixed_array() {
+        let mut node = JsonNode::parse(r#"[{"a": 1}, "b", 3, [2], 1.5, null, true, "a", [1, 2], false, 2, 2.0, -1]"#).unwrap();
+
+        node.sort_array();
+
+        assert_eq!(node.to_json_string(), r#"[null,false,true,-1,1.5,2,2.0,3,"a","b",[1,2],[2],{"a":1}]"#);
+        assert_eq!(node.as_array().unwrap()[5], JsonNode::Integer(2));
+        assert_eq!(node.as_array().unwrap()[6], JsonNode::Float(2.0));
+    }
+
+    #[test]
+    fn partial_cmp_is_consistent_with_eq() {
+        use std::cmp::Ordering;
+
+        assert_eq!(JsonNode::Float(-0.0).partial_cmp(&JsonNode::Float(0.0)), Some(Ordering::Equal));
+        assert_eq!(JsonNode::Float(f64::NAN).partial_cmp(&JsonNode::Float(f64::NAN)), Some(Ordering::Equal));
+        assert_eq!(JsonNode::Integer(1).partial_cmp(&JsonNode::Float(1.0)), Some(Ordering::Less));
+        assert_eq!(JsonNode::Float(1.0).partial_cmp(&JsonNode::Integer(1)), Some(Ordering::Greater));
+        assert!(JsonNode::Integer(i64::MAX) < JsonNode::Float(9_223_372_036_854_775_808.0));
+        assert!(JsonNode::Integer(3) > JsonNode::Float(2.5));
+        assert!(JsonNode::Integer(-3) < JsonNode::Float(-2.5));
+
+        assert_eq!(JsonNode::Float(-0.0).total_cmp(&JsonNode::Float(0.0)), Ordering::Equal);
+        assert_eq!(JsonNode::Float(f64::NAN).total_cmp(&JsonNode::Integer(i64::MAX)), Ordering::Greater);
+    }
+
+    #[test]
+    fn hash_floats_in_set() {
+        use std::collections::{HashMap, HashSet};
+
+        let mut set = HashSet::new();
+
+        assert!(set.insert(JsonNode::Float(1.5)));
+        assert!(!set.insert(JsonNode::Float(1.5)));
+        assert!(set.insert(JsonNode::Float(0.0)));
+        assert!(!set.insert(JsonNode::Float(-0.0)));
+        assert!(set.insert(JsonNode::Float(f64::NAN)));
+        assert!(!set.insert(JsonNode::Float(-f64::NAN)));
+        assert!(set.insert(JsonNode::Float(2.5)));
+        assert!(set.insert(JsonNode::Integer(2)));
+        assert!(set.insert(JsonNode::Float(2.0)));
+
+        assert_eq!(set.len(), 6);
+
+        let values = JsonNode::parse("[1, 2.5, 1, \"a\", 2.5, 1]").unwrap();
+        let mut frequencies = HashMap::new();
+
+        for value in values.as_array().unwrap() {
+            *frequencies.entry(value).or_insert(0) += 1;
+        }
+
+        assert_eq!(frequencies[&JsonNode::Integer(1)], 3);
+        assert_eq!(frequencies[&JsonNode::Float(2.5)], 2);
+        assert_eq!(frequencies[&JsonNode::String("a".to_owned())], 1);
+    }
+
+    #[test]
+    fn integers_beyond_i64_parse_as_uinteger() {
+        let node = JsonNode::parse("18446744073709551615").unwrap();
+
+        assert_eq!(node, JsonNode::UInteger(u64::MAX));
+        assert_eq!(node.to_json_string(), "18446744073709551615");
+        assert_eq!(JsonNode::parse(&node.to_json_string()).unwrap(), node);
+
+        assert_eq!(JsonNode::parse("9223372036854775807").unwrap(), JsonNode::Integer(i64::MAX));
+        assert_eq!(JsonNode::parse("9223372036854775808").unwrap(), JsonNode::UInteger(9_223_372_036_854_775_808));
+        assert!(JsonNode::parse("18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn uintegers_order_with_other_numbers() {
+        assert!(JsonNode::Integer(i64::MAX) < JsonNode::UInteger(u64::MAX));
+        assert!(JsonNode::UInteger(u64::MAX) < JsonNode::Float(1e20));
+        assert!(JsonNode::UInteger(u64::MAX) > JsonNode::Float(1.8e19));
+        assert!(JsonNode::Integer(1) < JsonNode::UInteger(1));
+    }
+
+    #[test]
+    fn floats_round_trip_bit_exact() {
+        let floats = [
+            0.1 + 0.2,
+            0.1,
+            1.0 / 3.0,
+            -2.5e-10,
+            123456789.12345678,
+            f64::EPSILON,
+            f64::MIN_POSITIVE,
+            5e-324,
+            1e300,
+            f64::MAX,
+        ];
+
+        for float in floats {
+            let json = JsonNode::Float(float).to_json_string();
+            let parsed = JsonNode::parse(&json).unwrap();
+
+            assert_eq!(parsed.as_float().map(|f| f.to_bits()), Some(float.to_bits()), "{} did not round-trip", json);
+        }
+
+        assert_eq!(JsonNode::Float(0.1 + 0.2).to_json_string(), "0.30000000000000004");
+    }
+
+    #[test]
+    fn to_jsonl_lines_reparse() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::parse(r#"[{"name": "Jason", "age": 30}, [1, 2.5], "three"]"#).unwrap();
+        let jsonl = node.to_jsonl().unwrap();
+        let lines = jsonl.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines.len(), 3);
+
+        for (line, element) in lines.iter().zip(node.as_array().unwrap()) {
+            assert_eq!(&JsonNode::parse(line).unwrap(), element);
+        }
+
+        assert_eq!(
+            JsonNode::Null.to_jsonl(),
+            Err(JsonNodeError::TypeMismatch { expected: "array", found: "null" })
+        );
+    }
+
+    #[cfg(feature = "undefined")]
+    #[test]
+    fn undefined_is_omitted_from_objects() {
+        use crate::JsonPropertyMap;
+
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("nickname".to_owned(), JsonNode::Undefined),
+            ("middleName".to_owned(), JsonNode::Null),
+        ]));
+
+        assert_eq!(node.to_json_string(), r#"{"name":"Jason","middleName":null}"#);
+        assert_eq!(node.to_json_string_pretty(), "{\n    \"name\": \"Jason\",\n    \"middleName\": null\n}");
+        assert_eq!(JsonNode::Array(vec![JsonNode::Undefined]).to_json_string(), "[null]");
+
+        assert_eq!(node.get_or_undefined("middleName"), &JsonNode::Null);
+        assert_eq!(node.get_or_undefined("age"), &JsonNode::Undefined);
+        assert!(JsonNode::Undefined < JsonNode::Null);
+    }
+
+    #[test]
+    fn display_is_json() {
+        use crate::JsonPropertyMap;
+
+        let string = JsonNode::String("b".to_owned());
+        let object = JsonNode::Object(JsonPropertyMap::from([
+            ("a".to_owned(), JsonNode::String("b".to_owned())),
+            ("numbers".to_owned(), JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Integer(2)])),
+            ("empty".to_owned(), JsonNode::Object(JsonPropertyMap::new())),
+        ]));
+
+        assert_eq!(format!("{}", string), "\"b\"");
+        assert_eq!(format!("{:#}", string), "\"b\"");
+        assert_eq!(format!("{}", object), r#"{"a":"b","numbers":[1,2],"empty":{}}"#);
+        assert_eq!(format!("{:#}", object), "{\n    \"a\": \"b\",\n    \"numbers\": [\n        1,\n        2\n    ],\n    \"empty\": {}\n}");
+    }
+
+    #[test]
+    fn write_json_pretty_with_crlf() {
+        use crate::{JsonPropertyMap, LineEnding, SerializeOptions};
+
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("numbers".to_owned(), JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::Integer(2)])),
+            ("empty".to_owned(), JsonNode::Object(JsonPropertyMap::new())),
+        ]));
+
+        let options = SerializeOptions {
+            indent: "\t".to_owned(),
+            line_ending: LineEnding::CrLf,
+            ..SerializeOptions::default()
+        };
+
+        let mut buffer = Vec::new();
+        node.write_json_pretty_with(&mut buffer, &options).unwrap();
+
+        let expected = "{\r\n\t\"name\": \"Jason\",\r\n\t\"numbers\": [\r\n\t\t1,\r\n\t\t2\r\n\t],\r\n\t\"empty\": {}\r\n}";
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+    }
+
+    #[test]
+    fn into_object_and_into_array() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        let mut object = node.clone().into_object().unwrap();
+        let children = object.remove("children").unwrap().into_array().unwrap();
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[1].as_object().unwrap().get("name"), Some(&JsonNode::String("Jasmine".to_owned())));
+
+        assert_eq!(node.clone().into_array(), Err(node));
+        assert_eq!(JsonNode::Array(Vec::new()).into_object(), Err(JsonNode::Array(Vec::new())));
+        assert_eq!(JsonNode::String("a".to_owned()).into_array(), Err(JsonNode::String("a".to_owned())));
+    }
+
+    #[test]
+    fn as_scalar_views() {
+        use crate::{JsonPropertyMap, JsonValue};
+
+        let integer = JsonNode::Integer(42);
+        let string = JsonNode::String("Jason".to_owned());
+
+        assert_eq!(integer.as_scalar(), Some(JsonValue::Integer(42)));
+        assert_eq!(string.as_scalar(), Some(JsonValue::String("Jason".to_owned())));
+        assert_eq!(JsonNode::Array(vec![integer.clone()]).as_scalar(), None);
+        assert_eq!(JsonNode::Object(JsonPropertyMap::new()).as_scalar(), None);
+        assert_eq!(JsonNode::from(string.as_scalar().unwrap()), string);
+    }
+
+    #[test]
+    fn to_json_string_escaping_forward_slashes() {
+        use crate::SerializeOptions;
+
+        let node = JsonNode::parse(r#"{"html": "<script>alert(1)<\/script>", "path": "a/b"}"#).unwrap();
+
+        let options = SerializeOptions {
+            escape_forward_slashes: true,
+            ..SerializeOptions::default()
+        };
+
+        assert_eq!(node.to_json_string(), r#"{"html":"<script>alert(1)</script>","path":"a/b"}"#);
+        assert_eq!(node.to_json_string_with_options(&options), r#"{"html":"<script>alert(1)<\/script>","path":"a\/b"}"#);
+        assert_eq!(JsonNode::parse(&node.to_json_string_with_options(&options)).unwrap(), node);
+    }
+
+    #[test]
+    fn map_keys_leaves_values() {
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        node.map_keys(|key| key.to_uppercase());
+
+        assert_eq!(node.get_path("CHILDREN.1.NAME"), Some(&JsonNode::String("Jasmine".to_owned())));
+        assert_eq!(node.get_path("NAME"), Some(&JsonNode::String("Jason".to_owned())));
+        assert_eq!(node.get_path("name"), None);
+
+        node.map_strings(|value| value.to_lowercase());
+
+        assert_eq!(node.get_path("CHILDREN.0.NAME"), Some(&JsonNode::String("jason jr.".to_owned())));
+        assert!(node.contains_key("ISMALE"));
+    }
+
+    #[test]
+    fn compact_floats_strips_trailing_zeros() {
+        use crate::ParseOptions;
+
+        let mut floats = JsonNode::Array(vec![JsonNode::Float(1.2000), JsonNode::Float(3.0)]);
+        floats.compact_floats();
+        assert_eq!(floats.to_json_string(), "[1.2,3.0]");
+
+        let options = ParseOptions {
+            preserve_number_text: true,
+            ..ParseOptions::default()
+        };
+
+        let mut node = JsonNode::parse_with_options(r#"{"a": 1.2000, "b": [3.000, -0.50E-2, 10, 18446744073709551616.100]}"#, &options).unwrap();
+        node.compact_floats();
+        assert_eq!(node.to_json_string(), r#"{"a":1.2,"b":[3.0,-0.5E-2,10,18446744073709551616.1]}"#);
+    }
+
+    #[test]
+    fn to_json_string_ascii_only() {
+        use crate::{JsonPropertyMap, SerializeOptions};
+
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("café".to_owned(), JsonNode::String("é and 😀".to_owned())),
+        ]));
+
+        let options = SerializeOptions {
+            ascii_only: true,
+            ..SerializeOptions::default()
+        };
+
+        let json = node.to_json_string_with_options(&options);
+
+        assert_eq!(json, r#"{"caf\u00e9":"\u00e9 and \ud83d\ude00"}"#);
+        assert!(json.is_ascii());
+        assert_eq!(JsonNode::parse(&json).unwrap(), node);
+        assert_eq!(node.to_json_string(), r#"{"café":"é and 😀"}"#);
+    }
+
+    #[test]
+    fn to_json_string_with_fixed_precision() {
+        use crate::{FloatFormat, SerializeOptions};
+
+        let node = JsonNode::parse(r#"{"sum": 0.30000000000000004, "big": 1234.5678, "count": 3, "nested": [2.5]}"#).unwrap();
+
+        let decimals = SerializeOptions {
+            float_format: FloatFormat::Decimals(2),
+            ..SerializeOptions::default()
+        };
+
+        let significant = SerializeOptions {
+            float_format: FloatFormat::SignificantDigits(3),
+            ..SerializeOptions::default()
+        };
+
+        assert_eq!(node.to_json_string_with_options(&decimals), r#"{"sum":0.30,"big":1234.57,"count":3,"nested":[2.50]}"#);
+        assert_eq!(node.to_json_string_with_options(&significant), r#"{"sum":0.3,"big":1230.0,"count":3,"nested":[2.5]}"#);
+        assert_eq!(node.to_json_string_with_options(&SerializeOptions::default()), node.to_json_string());
+
+        let mut buffer = Vec::new();
+        node.write_json_pretty_with(&mut buffer, &decimals).unwrap();
+        assert!(String::from_utf8(buffer).unwrap().contains("\"sum\": 0.30"));
+    }
+
+    #[test]
+    fn integral_floats_round_trip_as_floats() {
+        for float in [2.0, -3.0, 0.0, 1e20, 1e300] {
+            let json = JsonNode::Float(float).to_json_string();
+            assert_eq!(JsonNode::parse(&json).unwrap(), JsonNode::Float(float), "{}", json);
+        }
+
+        assert_eq!(JsonNode::Float(2.0).to_json_string(), "2.0");
+        assert_eq!(JsonNode::Integer(2).to_json_string(), "2");
+        assert_eq!(JsonNode::Float(2.0).to_json_string_pretty(), "2.0");
+    }
+
+    #[test]
+    fn to_json_string_integral_floats_keep_decimal() {
+        use crate::{FloatFormat, SerializeOptions};
+
+        let node = JsonNode::Array(vec![JsonNode::Float(2.0), JsonNode::Float(-0.0), JsonNode::Float(1e300), JsonNode::Float(0.5), JsonNode::Integer(2)]);
+
+        let options = SerializeOptions {
+            integral_floats_keep_decimal: true,
+            ..SerializeOptions::default()
+        };
+
+        let json = node.to_json_string_with_options(&options);
+        assert_eq!(JsonNode::parse(&json).unwrap(), node);
+        assert!(json.starts_with("[2.0,-0.0,"));
+        assert!(json.ends_with(",0.5,2]"));
+
+        let rounded = SerializeOptions {
+            float_format: FloatFormat::Decimals(0),
+            integral_floats_keep_decimal: true,
+            ..SerializeOptions::default()
+        };
+
+        assert_eq!(JsonNode::Float(2.4).to_json_string_with_options(&rounded), "2.0");
+
+        let without_decimal = SerializeOptions {
+            integral_floats_keep_decimal: false,
+            ..SerializeOptions::default()
+        };
+
+        assert_eq!(JsonNode::Float(2.0).to_json_string_with_options(&without_decimal), "2");
+    }
+
+    #[test]
+    fn parse_or_falls_back_to_default() {
+        use crate::JsonPropertyMap;
+
+        let default = JsonNode::Object(JsonPropertyMap::from([("name".to_owned(), JsonNode::String("Jason".to_owned()))]));
+
+        assert_eq!(JsonNode::parse_or("", default.clone()), default);
+        assert_eq!(JsonNode::parse_or(" \n\t", default.clone()), default);
+        assert_eq!(JsonNode::parse_or("\u{FEFF} ", default.clone()), default);
+        assert_eq!(JsonNode::parse_or("{\"name\":", default.clone()), default);
+        assert_eq!(JsonNode::parse_or("[true]", default), JsonNode::Array(vec![JsonNode::Boolean(true)]));
+    }
+
+    #[test]
+    fn parse_or_with_options_reports_invalid_json() {
+        use crate::{JsonNodeError, ParseOptions};
+
+        let default = JsonNode::Array(Vec::new());
+        let strict = ParseOptions::default();
+
+        assert_eq!(JsonNode::parse_or_with_options(" ", default.clone(), &strict), Ok(default.clone()));
+        assert_eq!(JsonNode::parse_or_with_options("{\"name\":", default.clone(), &strict), Err(JsonNodeError::UnexpectedEndOfInput { position: 8 }));
+        assert_eq!(JsonNode::parse_or_with_options("[1,]", default.clone(), &strict), Err(JsonNodeError::EmptyJson(Some(Box::new("[1,]".to_owned())))));
+
+        let lenient = ParseOptions {
+            fallback_on_error: true,
+            lenient_numbers: true,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(JsonNode::parse_or_with_options("[1,]", default.clone(), &lenient), Ok(default.clone()));
+        assert_eq!(JsonNode::parse_or_with_options("[.5]", default, &lenient), Ok(JsonNode::Array(vec![JsonNode::Float(0.5)])));
+    }
+
+    #[test]
+    fn from_slice_checks_utf8() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::from_slice("\u{FEFF}{\"name\": \"Jürgen\"}".as_bytes()).unwrap();
+        assert_eq!(node, JsonNode::parse(r#"{"name": "Jürgen"}"#).unwrap());
+
+        assert_eq!(JsonNode::from_slice(b"{\"name\": \"J\xFCrgen\"}"), Err(JsonNodeError::InvalidUtf8 { position: 11 }));
+        assert_eq!(JsonNode::from_slice(b"[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+    }
+
+    #[test]
+    fn map_leaves_uppercases_strings() {
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let mut visited = 0;
+
+        node.map_leaves(|leaf| {
+            visited += 1;
+
+            if let JsonNode::String(value) = leaf {
+                *value = value.to_uppercase();
+            }
+        });
+
+        let expected = SAMPLE_JSON
+            .replace(r#""Jason""#, r#""JASON""#)
+            .replace(r#""Jason Jr.""#, r#""JASON JR.""#)
+            .replace(r#""Jasmine""#, r#""JASMINE""#);
+
+        assert_eq!(node, JsonNode::parse(&expected).unwrap());
+        assert_eq!(visited, 17);
+    }
+
+    #[test]
+    fn remove_nulls_prunes_object_properties() {
+        let mut node = JsonNode::parse(r#"
+        {
+            "name": "Jason",
+            "spouse": null,
+            "age": 30,
+            "nickname": null,
+            "address": {"street": null, "city": "Oslo"},
+            "numbers": [1, null, {"a": null}],
+            "middleName": null
+        }"#).unwrap();
+
+        node.remove_nulls();
+
+        let expected = JsonNode::parse(r#"{"name": "Jason", "age": 30, "address": {"city": "Oslo"}, "numbers": [1, null, {}]}"#).unwrap();
+
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    fn get_path_or_falls_back_to_default() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let default = JsonNode::String("unknown".to_owned());
+
+        assert_eq!(node.get_path_or("children.1.name", &default), &JsonNode::String("Jasmine".to_owned()));
+        assert_eq!(node.get_path_or("children.2.name", &default), &default);
+        assert_eq!(node.get_path_or("name.first", &default), &default);
+    }
+
+    #[test]
+    fn collect_into_array() {
+        use crate::ToJsonNode;
+
+        let expected = JsonNode::Array(vec![JsonNode::Integer(0), JsonNode::Integer(1), JsonNode::Integer(2)]);
+
+        assert_eq!((0..3).map(|n: i64| n.to_json_node()).collect::<JsonNode>(), expected);
+        assert_eq!((0..3).collect::<JsonNode>(), expected);
+        assert_eq!(Vec::<i64>::new().into_iter().collect::<JsonNode>(), JsonNode::Array(Vec::new()));
+
+        let mut node = (0..1).collect::<JsonNode>();
+        node.extend(1..3);
+
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    fn approx_eq_tolerates_float_differences() {
+        let a = JsonNode::parse(r#"{"name": "Jason", "height": 1.8, "numbers": [1, 2.5, 3], "child": {"age": 5, "height": 1.2}}"#).unwrap();
+        let within = JsonNode::parse(r#"{"child": {"height": 1.2000001, "age": 5.0}, "numbers": [1.0, 2.4999999, 3], "height": 1.8000001, "name": "Jason"}"#).unwrap();
+        let outside = JsonNode::parse(r#"{"name": "Jason", "height": 1.81, "numbers": [1, 2.5, 3], "child": {"age": 5, "height": 1.2}}"#).unwrap();
+
+        assert!(a.approx_eq(&within, 1e-6));
+        assert!(!a.approx_eq(&within, 1e-9));
+        assert!(!a.approx_eq(&outside, 1e-6));
+        assert!(!a.approx_eq(&JsonNode::parse(r#"{"name": "Jason"}"#).unwrap(), 1e-6));
+    }
+
+    #[test]
+    fn approx_eq_compares_integers_and_floats_by_value() {
+        assert!(JsonNode::Integer(2).approx_eq(&JsonNode::Float(2.0), 0.0));
+        assert!(JsonNode::Float(2.0).approx_eq(&JsonNode::UInteger(2), 0.0));
+        assert!(JsonNode::Integer(2).approx_eq(&JsonNode::Float(2.05), 0.1));
+        assert!(!JsonNode::Integer(2).approx_eq(&JsonNode::Integer(3), 10.0));
+        assert!(JsonNode::Integer(i64::MAX).approx_eq(&JsonNode::UInteger(i64::MAX as u64), 0.0));
+        assert!(JsonNode::Float(f64::NAN).approx_eq(&JsonNode::Float(f64::NAN), 0.0));
+        assert!(!JsonNode::Float(f64::INFINITY).approx_eq(&JsonNode::Float(f64::NEG_INFINITY), f64::INFINITY));
+        assert!(!JsonNode::Integer(0).approx_eq(&JsonNode::Boolean(false), 1.0));
+        assert!(!JsonNode::String("1".to_owned()).approx_eq(&JsonNode::Integer(1), 1.0));
+    }
+
+    #[test]
+    fn try_to_json_string_rejects_non_finite_floats() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        assert_eq!(node.try_to_json_string(), Ok(node.to_json_string()));
+
+        let mut node = JsonNode::parse(r#"{"a": [1.5, {"b": 0.0}]}"#).unwrap();
+        node.map_leaves(|leaf| {
+            if leaf == &JsonNode::Float(0.0) {
+                *leaf = JsonNode::Float(f64::NEG_INFINITY);
+            }
+        });
+
+        assert_eq!(node.try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("-inf".to_owned())));
+        assert_eq!(JsonNode::Float(f64::NAN).try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("NaN".to_owned())));
+    }
+
+    #[test]
+    fn try_to_json_string_with_empty_and_single_property_containers() {
+        use crate::JsonNodeError;
+
+        for json in [r#"{"tags": [], "a": 1}"#, "[1, {}]", r#"{"a": 1}"#, "[]", "{}", r#"{"a": {"b": [[]]}}"#] {
+            let node = JsonNode::parse(json).unwrap();
+            assert_eq!(node.try_to_json_string(), Ok(node.to_json_string()));
+        }
+
+        let mut node = JsonNode::parse(r#"{"tags": [], "a": {"b": 0.0}}"#).unwrap();
+        node.map_leaves(|leaf| *leaf = JsonNode::Float(f64::INFINITY));
+
+        assert_eq!(node.try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("inf".to_owned())));
+    }
+
+    #[test]
+    fn debug_pretty_shows_indented_json() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let pretty = node.debug_pretty().to_string();
+
+        assert_eq!(pretty, node.to_json_string_pretty());
+        assert!(pretty.starts_with("{\n    \"name\": \"Jason\",\n"));
+        assert_eq!(JsonNode::parse(&pretty).unwrap(), node);
+        assert!(format!("{:?}", node).starts_with("Object("));
+    }
+
+    #[test]
+    fn to_json_string_with_partial_key_order() {
+        use crate::SerializeOptions;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        let expected = concat!(
+            r#"{"name":"Jason","children":["#,
+            r#"{"name":"Jason Jr.","age":5,"isMale":true,"height":1.2},"#,
+            r#"{"name":"Jasmine","age":3,"isMale":false,"height":1.1}"#,
+            r#"],"age":30,"isMale":true,"height":1.8,"numbers":[1,2,3,4,5]}"#,
+        );
+
+        assert_eq!(node.to_json_string_with_key_order(&["name", "unknown", "children"]), expected);
+        assert_eq!(node.to_json_string_with_key_order(&[]), node.to_json_string());
+
+        let sorted = SerializeOptions {
+            sort_keys: true,
+            key_order: vec!["numbers".to_owned()],
+            ..SerializeOptions::default()
+        };
+
+        assert!(node.to_json_string_with_options(&sorted).starts_with(r#"{"numbers":[1,2,3,4,5],"age":30,"children":[{"age":5,"#));
+    }
+    #[test]
+    fn to_json_string_truncated_replaces_deep_containers() {
+        use crate::SerializeOptions;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(
+            node.to_json_string_truncated(2),
+            r#"{"name":"Jason","age":30,"isMale":true,"height":1.8,"numbers":[1,2,3,4,5],"children":["...","..."]}"#
+        );
+
+        assert_eq!(node.to_json_string_truncated(3), node.to_json_string());
+        assert_eq!(node.to_json_string_truncated(0), r#""...""#);
+
+        let options = SerializeOptions {
+            indent: "  ".to_owned(),
+            max_depth: Some(1),
+            truncation_placeholder: "null".to_owned(),
+            ..SerializeOptions::default()
+        };
+
+        let mut buffer = Vec::new();
+        node.write_json_pretty_with(&mut buffer, &options).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "{\n  \"name\": \"Jason\",\n  \"age\": 30,\n  \"isMale\": true,\n  \"height\": 1.8,\n  \"numbers\": null,\n  \"children\": null\n}"
+        );
+    }
+    #[test]
+    fn type_histogram_counts_every_node() {
+        let histogram = JsonNode::parse(SAMPLE_JSON).unwrap().type_histogram();
+
+        assert_eq!(histogram["string"], 3);
+        assert_eq!(histogram["integer"], 8);
+        assert_eq!(histogram["float"], 3);
+        assert_eq!(histogram["boolean"], 3);
+        assert_eq!(histogram["object"], 3);
+        assert_eq!(histogram["array"], 2);
+        assert_eq!(histogram.values().sum::<usize>(), 22);
+    }
+    #[test]
+    fn get_by_key_and_index() {
+        use crate::Accessor;
+
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(node.get_by(Accessor::Key("age")), Some(&JsonNode::Integer(30)));
+        assert_eq!(node.get_by(Accessor::Index(0)), None);
+
+        let numbers = node.get_by(Accessor::Key("numbers")).unwrap();
+
+        assert_eq!(numbers.get_by(Accessor::Index(4)), Some(&JsonNode::Integer(5)));
+        assert_eq!(numbers.get_by(Accessor::Index(5)), None);
+        assert_eq!(numbers.get_by(Accessor::Key("0")), None);
+
+        let path = [Accessor::Key("children"), Accessor::Index(1), Accessor::Key("name")];
+        let name = path.iter().try_fold(&mut node, |node, accessor| node.get_by_mut(*accessor)).unwrap();
+        *name = JsonNode::String("Jasmine Jr.".to_owned());
+
+        assert_eq!(node.get_path("children.1.name"), Some(&JsonNode::String("Jasmine Jr.".to_owned())));
+    }
+    #[test]
+    fn as_vec_of_converts_every_element() {
+        use crate::JsonNodeError;
+
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(node.get_path("numbers").unwrap().as_vec_of::<i64>(), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(node.as_vec_of::<i64>(), Err(JsonNodeError::TypeMismatch { expected: "array", found: "object" }));
+
+        let mixed = JsonNode::parse(r#"[1, 2.5, null]"#).unwrap();
+
+        assert_eq!(mixed.as_vec_of::<f64>(), Err(JsonNodeError::InElement {
+            index: 2,
+            error: Box::new(JsonNodeError::TypeMismatch { expected: "number", found: "null" }),
+        }));
+        assert_eq!(mixed.as_vec_of::<Option<f64>>(), Ok(vec![Some(1.0), Some(2.5), None]));
+    }
+    #[test]
+    fn raw_string_round_trips_as_a_string() {
+        use crate::JsonPropertyMap;
+
+        let node = JsonNode::Object(JsonPropertyMap::from([
+            ("id".to_owned(), JsonNode::raw_string("007")),
+            ("code".to_owned(), JsonNode::raw_string("1e3")),
+        ]));
+
+        let json = node.to_json_string();
+        assert_eq!(json, r#"{"id":"007","code":"1e3"}"#);
+
+        let parsed = JsonNode::parse(&json).unwrap();
+        assert_eq!(parsed, node);
+        assert_eq!(parsed.get_path("id").unwrap().as_string(), Some("007"));
+        assert!(!parsed.get_path("code").unwrap().is_float());
+    }
+    #[test]
+    fn array_truncate_and_split_off() {
+        use crate::JsonNodeError;
+
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let numbers = node.as_object_mut().unwrap().get_mut("numbers").unwrap();
+
+        let tail = numbers.array_split_off(3).unwrap();
+        assert_eq!(tail.to_json_string(), "[4,5]");
+        assert_eq!(numbers.to_json_string(), "[1,2,3]");
+
+        numbers.array_truncate(10).unwrap();
+        assert_eq!(numbers.to_json_string(), "[1,2,3]");
+
+        numbers.array_truncate(1).unwrap();
+        assert_eq!(numbers.to_json_string(), "[1]");
+
+        let mismatch = JsonNodeError::TypeMismatch { expected: "array", found: "object" };
+
+        assert_eq!(node.array_truncate(0), Err(mismatch.clone()));
+        assert_eq!(node.array_split_off(0), Err(mismatch));
     }
+    #[test]
+    fn push_converted_values() {
+        use crate::{JsonNodeError, JsonValue};
 
-    /// Extracts the inner `mut f64` contained inside the node if it is the `JsonNode::Float` discriminant.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use json_node::JsonNode;
-    /// 
-    /// let mut float_value = JsonNode::Float(3.14);
-    /// let mut non_float_value = JsonNode::Null;
-    /// 
-    /// assert_eq!(float_value.as_float_mut(), Some(&mut 3.14));
-    /// assert_eq!(non_float_value.as_float_mut(), None);
-    /// ```
-    pub fn as_float_mut(&mut self) -> Option<&mut f64> {
-        match self {
-            JsonNode::Float(value) => Some(value),
-            _ => None,
-        }
+        let mut node = JsonNode::parse("[]").unwrap();
+        node.push(JsonValue::Boolean(true)).unwrap();
+        node.push_value(42).unwrap();
+        node.push_value(Some("Jason")).unwrap();
+        node.push_value(None::<i64>).unwrap();
+
+        assert_eq!(node.to_json_string(), r#"[true,42,"Jason",null]"#);
+
+        let mut scalar = JsonNode::Integer(1);
+        let mismatch = JsonNodeError::TypeMismatch { expected: "array", found: "integer" };
+
+        assert_eq!(scalar.push(JsonValue::Null), Err(mismatch.clone()));
+        assert_eq!(scalar.push_value("two"), Err(mismatch));
+        assert_eq!(scalar, JsonNode::Integer(1));
     }
+    #[test]
+    fn remove_empty_containers_reaches_a_fixed_point() {
+        let mut node = JsonNode::parse(r#"
+        {
+            "name": "Jason",
+            "empty": {},
+            "nested": {"a": {"b": {"c": []}}, "d": [[], [{}], 0]},
+            "children": [{"toys": []}, {"toys": ["ball"]}]
+        }"#).unwrap();
 
-    /// Extracts the inner `mut bool` contained inside the node if it is the `JsonNode::Boolean` discriminant.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use json_node::JsonNode;
-    /// 
-    /// let mut bool_value = JsonNode::Boolean(true);
-    /// let mut non_bool_value = JsonNode::Null;
-    /// 
-    /// assert_eq!(bool_value.as_boolean_mut(), Some(&mut true));
-    /// assert_eq!(non_bool_value.as_boolean_mut(), None);
-    /// ```
-    pub fn as_boolean_mut(&mut self) -> Option<&mut bool> {
-        match self {
-            JsonNode::Boolean(value) => Some(value),
-            _ => None,
-        }
+        node.remove_empty_containers();
+
+        assert_eq!(node.to_json_string(), r#"{"name":"Jason","nested":{"d":[0]},"children":[{"toys":["ball"]}]}"#);
+
+        let mut root = JsonNode::parse(r#"[[], {"a": {}}]"#).unwrap();
+        root.remove_empty_containers();
+
+        assert_eq!(root, JsonNode::Array(Vec::new()));
     }
+    #[test]
+    fn into_json_string_matches_to_json_string() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let expected = node.to_json_string();
 
-    /// Convert the node tree to a JSON string.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use json_node::JsonNode;
-    /// 
-    /// // Create a JsonNode tree.
-    /// let node_tree = JsonNode::Array(Vec::from([
-    ///     JsonNode::Integer(0),
-    ///     JsonNode::Float(0.5),
-    ///     JsonNode::Integer(1),
-    ///     JsonNode::Null,
-    ///     JsonNode::Boolean(false)
-    /// ]));
-    /// 
-    /// let json_string = node_tree.to_json_string();
-    /// 
-    /// assert_eq!(json_string, "[0,0.5,1,null,false]".to_owned());
-    /// ```
-    /// 
-    /// # Remarks
-    /// 
-    /// This function does zero formatting. The entire JSON string is returned without any spaces or new-lines.
-    pub fn to_json_string(&self) -> String {
-        match self {
-            JsonNode::String(value) => value.to_string().to_string().surround_with("\"", "\""),
-            JsonNode::Integer(value) => value.to_string(),
-            JsonNode::Float(value) => value.to_string(),
-            JsonNode::Boolean(value) => value.to_string(),
-            JsonNode::Null => String::from("null"),
-            JsonNode::Object(object) => object.to_json_string(),
-            JsonNode::Array(array) => {
-                array
-                .iter()
-                .map(|node| node.to_json_string())
-                .collect::<Vec<String>>()
-                .join(",")
-                .surround_with("[", "]")
-            },
-        }
+        assert_eq!(node.into_json_string(), expected);
+        assert_eq!(JsonNode::parse(r#"{"a": {}, "b": [], "c": "\"quoted\""}"#).unwrap().into_json_string(), r#"{"a":{},"b":[],"c":"\"quoted\""}"#);
     }
-    
-}
+    #[test]
+    fn to_json_bytes_is_the_utf8_of_to_json_string() {
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        node.as_object_mut().unwrap().add("greeting", JsonNode::String("héllo ✓".to_owned()));
 
-impl<'a> IntoIterator for &'a JsonNode {
-    type Item = &'a JsonNode;
-    type IntoIter = Iter<'a>;
+        assert_eq!(node.to_json_bytes(), node.to_json_string().as_bytes());
+    }
+    #[test]
+    fn compact_round_trip_has_no_spaces() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+        let json = node.to_json_string();
 
-    /// Turns the node tree into an iterator which iterates over evey `JsonNode` in the tree in a depth first manner.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use json_node::JsonNode;
-    ///     
-    /// let node_tree = JsonNode::Array(Vec::from([
-    ///     JsonNode::Array(Vec::from([                     // First element is an array with the value `1` inside.
-    ///         JsonNode::Integer(1),
-    ///     ])),
-    ///     JsonNode::Integer(2),         // Second element is the value `2`.
-    ///     JsonNode::Array(Vec::from([
-    ///         JsonNode::Integer(3)      // Third element is an array with the value `3` inside.
-    ///     ]))
-    /// ]));
-    /// 
-    /// let sequence = node_tree.into_iter().collect::<Vec<&JsonNode>>();
-    /// 
-    /// let expected = vec![
-    ///     &JsonNode::Integer(1),
-    ///     &JsonNode::Integer(2),
-    ///     &JsonNode::Integer(3)
-    /// ];
-    /// 
-    /// assert_eq!(sequence, expected);
-    /// ```
-    fn into_iter(self) -> Self::IntoIter {
-        Iter {
-            node: Some(self),
-            array_index: None,
-            object_index: None,
-            child: None,
-        }
+        // The only space left is the one inside the string "Jason Jr.".
+        assert_eq!(json.matches(' ').count(), 1);
+        assert!(json.contains(r#""name":"Jason Jr.""#));
+        assert_eq!(node.as_object().unwrap().to_json_string(), json);
+        assert_eq!(JsonNode::parse(&json).unwrap().to_json_string(), json);
     }
-}
+    #[test]
+    fn serialize_deeply_nested_array() {
+        const DEPTH: usize = 50_000;
 
-pub struct Iter<'a> {
-    node: Option<&'a JsonNode>,
-    array_index: Option<usize>,
-    object_index: Option<usize>,
-    child: Option<Box<Iter<'a>>>,
-}
+        let mut node = JsonNode::Array(Vec::new());
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = &'a JsonNode;
+        for _ in 1..DEPTH {
+            node = JsonNode::Array(vec![node]);
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(iter) = &mut self.child {
-            if let Some(node) = iter.next() {
-                return Some(node);
-            }
+        assert_eq!(node.to_json_string(), "[".repeat(DEPTH) + &"]".repeat(DEPTH));
+    }
 
-            self.child = None;
-        }
+    #[test]
+    fn try_to_json_string_deeply_nested_array() {
+        use crate::JsonNodeError;
 
-        if let None = self.node {
-            return None; // Termination point for iteration. If the iterator has recursed, this allows the parent iterator to continue.
-        }
+        const DEPTH: usize = 200_000;
 
-        match self.node.unwrap() {
-            JsonNode::Array(nodes) => {
-                match self.array_index {
-                    Some(mut index) => {
-                        index = index + 1;
-                        self.array_index = Some(index);
-                        self.child = Some(Box::new(nodes[index].into_iter()));
-                        let next = self.next();
+        let mut node = JsonNode::Array(vec![JsonNode::Float(1.5)]);
 
-                        if index == nodes.len() - 1 {
-                            self.array_index = None;
-                            self.node = None;
-                        }
+        for _ in 1..DEPTH {
+            node = JsonNode::Array(vec![node]);
+        }
 
-                        return next;
-                    },
-                    None => {
-                        self.array_index = Some(0);
-                        self.child = Some(Box::new(nodes[0].into_iter()));
-                        let next = self.next();
+        assert_eq!(node.try_to_json_string(), Ok("[".repeat(DEPTH) + "1.5" + &"]".repeat(DEPTH)));
 
-                        if nodes.len() == 1 {
-                            self.array_index = None;
-                            self.node = None;
-                        }
+        node = JsonNode::Array(vec![node, JsonNode::Float(f64::NAN)]);
+        assert_eq!(node.try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("NaN".to_owned())));
+    }
 
-                        return next;
-                    },
-                }
-            },
-            JsonNode::Object(properties) => {
-                match self.object_index {
-                    Some(mut index) => {
-                        index = index + 1;
-                        self.object_index = Some(index);
-                        self.child = Some(Box::new(properties[index].1.into_iter()));
-                        let next = self.next();
+    #[test]
+    fn drop_deeply_nested_trees() {
+        use crate::JsonPropertyMap;
 
-                        if index == properties.len() - 1 {
-                            self.object_index = None;
-                            self.node = None;
-                        }
+        const DEPTH: usize = 100_000;
 
-                        return next;
-                    },
-                    None => {
-                        self.object_index = Some(0);
-                        self.child = Some(Box::new(properties[0].1.into_iter()));
-                        let next = self.next();
+        let arrays = JsonNode::parse(&("[".repeat(DEPTH) + &"]".repeat(DEPTH))).unwrap();
+        drop(arrays);
 
-                        if properties.len() == 1 {
-                            self.object_index = None;
-                        }
+        let objects = JsonNode::parse(&(r#"{"a": "#.repeat(DEPTH) + "[1, {}]" + &"}".repeat(DEPTH))).unwrap();
+        drop(objects);
 
-                        return next;
-                    },
-                }
-            },
-            _ => {
-                let node = self.node.unwrap();
-                self.node = None;
-                Some(node)
-            },
+        let mut mixed = JsonNode::Null;
+
+        for depth in 0..DEPTH {
+            mixed = match depth % 2 {
+                0 => JsonNode::Array(vec![JsonNode::Integer(1), mixed]),
+                _ => JsonNode::Object(JsonPropertyMap::from(vec![("child".to_owned(), mixed)])),
+            };
         }
+
+        drop(mixed);
     }
-}
+    #[test]
+    fn any_and_all_leaves() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
 
-impl Display for JsonNode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            JsonNode::String(value) => write!(f, "{}", value),
-            JsonNode::Integer(value) => write!(f, "{}", value),
-            JsonNode::Float(value) => write!(f, "{}", value),
-            JsonNode::Boolean(value) => write!(f, "{}", value),
-            JsonNode::Null => write!(f, "null"),
-            JsonNode::Object(object) => write!(f, "{}", object.to_json_string()),
-            JsonNode::Array(array) => write!(f, "{}", {
-                array
-                .iter()
-                .map(|node| node.to_json_string())
-                .collect::<Vec<String>>()
-                .join(",")
-                .surround_with("[", "]")
-            }),
-        }
+        assert!(!node.any_leaf(|leaf| leaf.is_null()));
+        assert!(node.any_leaf(|leaf| leaf.as_string() == Some("Jasmine")));
+
+        let with_null = JsonNode::parse(r#"{"children": [{"spouse": null}]}"#).unwrap();
+        assert!(with_null.any_leaf(|leaf| leaf.is_null()));
+
+        let numbers = JsonNode::parse("[1, [2, 3], {\"four\": 4}]").unwrap();
+        assert!(numbers.all_leaves(|leaf| leaf.is_integer()));
+        assert!(!JsonNode::parse("[1, 2.5, 3]").unwrap().all_leaves(|leaf| leaf.is_integer()));
+        assert!(!node.all_leaves(|leaf| leaf.is_integer()));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::JsonNode;
+    #[test]
+    fn any_leaf_short_circuits() {
+        use std::cell::Cell;
+
+        let visited = Cell::new(0);
+        let node = JsonNode::parse("[null, 1, 2, 3]").unwrap();
+
+        assert!(node.any_leaf(|leaf| {
+            visited.set(visited.get() + 1);
+            leaf.is_null()
+        }));
+        assert_eq!(visited.get(), 1);
+    }
 
     #[test]
-    fn iterate_works() {
-        let json = r#"
-        {
-            "name": "Jason",
-            "age": 30,
-            "isMale": true,
-            "height": 1.8,
-            "numbers": [1, 2, 3, 4, 5],
-            "children": [
-                {
-                    "name": "Jason Jr.",
-                    "age": 5,
-                    "isMale": true,
-                    "height": 1.2
-                },
-                {
-                    "name": "Jasmine",
-                    "age": 3,
-                    "isMale": false,
-                    "height": 1.1
-                }
-            ]
-        }"#;
+    fn empty_container_constructors() {
+        assert!(JsonNode::new_object().is_object());
+        assert!(JsonNode::new_array().is_array());
+        assert!(JsonNode::object_with_capacity(4).is_object());
+        assert!(JsonNode::array_with_capacity(4).is_array());
 
-        let node = JsonNode::parse(json).unwrap();
-        for e in node.into_iter() {
-            println!("{:?}", e)
-        }
+        assert_eq!(JsonNode::new_object().to_json_string(), "{}");
+        assert_eq!(JsonNode::array_with_capacity(4).to_json_string(), "[]");
     }
+
 }
 
 #[cfg(test)]
@@ -732,12 +4243,13 @@ mod doc_tests{
         
         let sequence = node_tree.into_iter().collect::<Vec<&JsonNode>>();
 
-        let expected = vec![
-            &JsonNode::Integer(1),
-            &JsonNode::Integer(2),
-            &JsonNode::Integer(3)
+        let expected = [
+            JsonNode::Integer(1),
+            JsonNode::Integer(2),
+            JsonNode::Integer(3)
         ];
 
-        assert_eq!(sequence, expected);
+        assert_eq!(sequence, expected.iter().collect::<Vec<&JsonNode>>());
     }
+
 }
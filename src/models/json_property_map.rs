@@ -1,8 +1,10 @@
 use std::ops::{Index, IndexMut};
 
 use crate::{models::JsonNode, errors::JsonNodeError};
+use crate::serializing::{JsonNodeSerializer, SerializeOptions};
+use crate::ToJsonNode;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct JsonPropertyMap(Vec<(String, JsonNode)>);
 
 impl JsonPropertyMap {
@@ -11,6 +13,66 @@ impl JsonPropertyMap {
         Self(Vec::new())
     }
 
+    /// Create a new property map with no mappings which has room for at least `capacity` mappings without reallocating.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `capacity` - The number of mappings to preallocate room for.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonPropertyMap;
+    /// 
+    /// let map = JsonPropertyMap::with_capacity(10);
+    /// 
+    /// assert!(map.is_empty());
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Create a property map from pairs of names and values, converting every value with `ToJsonNode`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `pairs` - The names and values of the properties, in the order they should be in the map.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let map = JsonPropertyMap::from_pairs([("width", 1.5), ("height", 2.0)]);
+    /// 
+    /// assert_eq!(map.get("height"), Some(&JsonNode::Float(2.0)));
+    /// assert_eq!(map.to_json_string(), r#"{"width":1.5,"height":2.0}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// If a name appears more than once, the last value is kept at the position of the first, just like `Extend` does.
+    pub fn from_pairs<K: Into<String>, V: ToJsonNode>(pairs: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = Self::new();
+        map.extend(pairs.into_iter().map(|(key, value)| (key.into(), value.to_json_node())));
+        map
+    }
+
+    /// Reserves room for at least `additional` more mappings without reallocating.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `additional` - The number of mappings to reserve room for on top of the current length.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Returns the number of mappings the object can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
     /// Get the `JsonNode` associated with a name.
     /// 
     /// # Arguments
@@ -40,6 +102,35 @@ impl JsonPropertyMap {
               .map(|(_, v)| v)
     }
 
+    /// Get the `JsonNode` associated with a name, ignoring ASCII case, so `userId` also finds `userid` and `USERID`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - The name of the property you want, in any case.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let map = JsonPropertyMap::from([
+    ///     ("UserId".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// assert_eq!(map.get_ignore_case("userid"), Some(&JsonNode::Integer(42)));
+    /// assert_eq!(map.get("userid"), None);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// If several names match, the first one in insertion order wins, even if a later one matches the case exactly.
+    /// Only ASCII letters are compared case-insensitively, so non-ASCII letters like `É` and `é` must match exactly.
+    pub fn get_ignore_case(&self, property_name: &str) -> Option<&JsonNode> {
+        self.0.iter()
+              .find(|(k, _)| k.eq_ignore_ascii_case(property_name))
+              .map(|(_, v)| v)
+    }
+
     /// Get the `JsonNode` associated with a name as a mutable value.
     /// 
     /// # Arguments
@@ -98,13 +189,80 @@ impl JsonPropertyMap {
     /// assert_eq!(map, expected);
     /// ```
     pub fn add(&mut self, property_name: &str, json_node: JsonNode) {
-        if self.contains_property(&property_name) {
+        if self.contains_property(property_name) {
             return;
         }
 
         self.0.push((property_name.to_owned(), json_node));
     }
 
+    /// Inserts a mapping at `index`, shifting every later mapping one place towards the end.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `index` - The position the mapping gets in insertion order.
+    /// * `property_name` - Name of the property.
+    /// * `json_node` - The node to store under the name.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let mut map = JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// map.insert_at(0, "id".to_owned(), JsonNode::Integer(7));
+    /// 
+    /// assert_eq!(map.property_names(), vec!["id", "name", "age"]);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Unlike `JsonPropertyMap::add`, an existing property with the same name is replaced.
+    /// It is removed before inserting, so `index` counts the positions of the remaining mappings.
+    /// Inserting near the start is O(n) since every later mapping has to move.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is greater than the number of mappings, once any existing property with the same name is removed.
+    pub fn insert_at(&mut self, index: usize, property_name: String, json_node: JsonNode) {
+        self.0.retain(|(k, _)| *k != property_name);
+        self.0.insert(index, (property_name, json_node));
+    }
+
+    /// Removes a mapping in O(1) time by moving the last mapping into its place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the property to be removed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let mut map = JsonPropertyMap::from([
+    ///     ("a".to_owned(), JsonNode::Integer(1)),
+    ///     ("b".to_owned(), JsonNode::Integer(2)),
+    ///     ("c".to_owned(), JsonNode::Integer(3)),
+    /// ]);
+    /// 
+    /// assert_eq!(map.swap_remove("a"), Some(JsonNode::Integer(1)));
+    /// assert_eq!(map.property_names(), vec!["c", "b"]);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// This changes the order of the mappings, so only use it when order doesn't matter.
+    /// Use `JsonPropertyMap::remove` to keep the remaining mappings in insertion order.
+    /// If several properties have the name, only the first one is removed.
+    pub fn swap_remove(&mut self, property_name: &str) -> Option<JsonNode> {
+        self.position_of(property_name).map(|i| self.0.swap_remove(i).1)
+    }
+
     /// Removes a mapping from the object if it exists.
     /// 
     /// # Arguments
@@ -158,6 +316,54 @@ impl JsonPropertyMap {
         self.0.iter().any(|(k, _)| k == property_name)
     }
 
+    /// Gets the index of the property named `property_name` in insertion order.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - The name to look for.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let map = JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// assert_eq!(map.position_of("age"), Some(1));
+    /// assert_eq!(map.position_of("height"), None);
+    /// ```
+    pub fn position_of(&self, property_name: &str) -> Option<usize> {
+        self.0.iter().position(|(k, _)| k == property_name)
+    }
+
+    /// Gets the name of the first property, in insertion order, whose `JsonNode` equals `json_node`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `json_node` - The node to look for, compared with `==`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let map = JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// assert_eq!(map.key_of(&JsonNode::Integer(42)), Some("age"));
+    /// assert_eq!(map.key_of(&JsonNode::Null), None);
+    /// ```
+    pub fn key_of(&self, json_node: &JsonNode) -> Option<&str> {
+        self.0.iter()
+              .find(|(_, v)| v == json_node)
+              .map(|(k, _)| k.as_str())
+    }
+
     /// Gets all property names in the object.
     pub fn property_names(&self) -> Vec<&String> {
         self.0.iter().map(|(k, _)| k).collect()
@@ -177,18 +383,93 @@ impl JsonPropertyMap {
         self.0.iter_mut().map(|(_, v)| v).collect()
     }
 
+    /// Gets the mappings whose `JsonNode` matches a predicate, in insertion order.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `predicate` - Decides which nodes to keep.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let map = JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// let strings = map.entries_of_type(JsonNode::is_string);
+    /// 
+    /// assert_eq!(strings, vec![("name", &JsonNode::String("John Doe".to_owned()))]);
+    /// ```
+    pub fn entries_of_type(&self, predicate: impl Fn(&JsonNode) -> bool) -> Vec<(&str, &JsonNode)> {
+        self.0.iter()
+              .filter(|(_, v)| predicate(v))
+              .map(|(k, v)| (k.as_str(), v))
+              .collect()
+    }
+
+    /// Keeps only the mappings which match a predicate, removing the rest while keeping the insertion order.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `predicate` - Decides which mappings to keep from their name and node.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let mut map = JsonPropertyMap::from([
+    ///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+    ///     ("age".to_owned(), JsonNode::Integer(42)),
+    /// ]);
+    /// 
+    /// map.retain(|_, node| node.is_integer());
+    /// 
+    /// assert_eq!(map, JsonPropertyMap::from([("age".to_owned(), JsonNode::Integer(42))]));
+    /// ```
+    pub fn retain(&mut self, mut predicate: impl FnMut(&str, &JsonNode) -> bool) {
+        self.0.retain(|(k, v)| predicate(k, v));
+    }
+
     /// Clears the map of all mappings.
     pub fn clear(&mut self) {
         self.0.clear();
     }
 
+    /// Removes every mapping from the object and returns them as owned tuples in insertion order, without cloning.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::{JsonNode, JsonPropertyMap};
+    /// 
+    /// let mut map = JsonPropertyMap::from([
+    ///     ("number".to_owned(), JsonNode::Integer(42))
+    /// ]);
+    /// 
+    /// let drained = map.drain().collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(drained, vec![("number".to_owned(), JsonNode::Integer(42))]);
+    /// assert!(map.is_empty());
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// The map is empty once the iterator is dropped, even if not every mapping was consumed.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, (String, JsonNode)> {
+        self.0.drain(..)
+    }
+
     /// Returns an iterator over the mappings represented as tuples.
-    pub fn iter(&self) -> std::slice::Iter<(String, JsonNode)> {
+    pub fn iter(&self) -> std::slice::Iter<'_, (String, JsonNode)> {
         self.0.iter()
     }
 
     /// Returns an iterator over the mappings represented as tuples that allows modifying each element and its name.
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<(String, JsonNode)> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, (String, JsonNode)> {
         self.0.iter_mut()
     }
     
@@ -208,16 +489,12 @@ impl JsonPropertyMap {
     /// 
     /// This function does zero formatting meaning the JSON string will have no spaces or new-lines.
     pub fn to_json_string(&self) -> String {
-        let mut result = "{".to_string();
-
-        for (key, value) in &self.0 {
-            result.push_str(&format!("\"{}\":{},", key, value.to_json_string()));
-        }
+        let mut buffer = Vec::new();
 
-        result.pop(); // Pops the trailing comma
-        result.push('}');
+        JsonNodeSerializer::write_compact_object(&mut buffer, self, &SerializeOptions::default(), 0)
+            .expect("writing to a Vec<u8> never fails");
 
-        result
+        String::from_utf8(buffer).expect("serialized JSON is always valid UTF-8")
     }
 }
 
@@ -241,6 +518,43 @@ impl FromIterator<(String, JsonNode)> for JsonPropertyMap {
     }
 }
 
+/// Extends the map with the mappings from an iterator.
+/// 
+/// Like the standard library maps, a property which already exists has its `JsonNode` overwritten in place, keeping its original position.
+/// New properties are appended in iteration order.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{JsonNode, JsonPropertyMap};
+/// 
+/// let mut map = JsonPropertyMap::from([
+///     ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+/// ]);
+/// 
+/// map.extend([
+///     ("name".to_owned(), JsonNode::String("Jane Doe".to_owned())),
+///     ("age".to_owned(), JsonNode::Integer(42)),
+/// ]);
+/// 
+/// let expected = JsonPropertyMap::from([
+///     ("name".to_owned(), JsonNode::String("Jane Doe".to_owned())),
+///     ("age".to_owned(), JsonNode::Integer(42)),
+/// ]);
+/// 
+/// assert_eq!(map, expected);
+/// ```
+impl Extend<(String, JsonNode)> for JsonPropertyMap {
+    fn extend<T: IntoIterator<Item = (String, JsonNode)>>(&mut self, iter: T) {
+        for (property_name, json_node) in iter {
+            match self.get_mut(&property_name) {
+                Some(existing) => *existing = json_node,
+                None => self.0.push((property_name, json_node)),
+            }
+        }
+    }
+}
+
 impl From<Vec<(String, JsonNode)>> for JsonPropertyMap {
     fn from(value: Vec<(String, JsonNode)>) -> Self {
         Self(value)
@@ -277,4 +591,195 @@ mod tests {
         
         assert_eq!(name, "JOHN DOE");
     }
+
+    #[test]
+    fn extend_overwrites_and_appends() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let mut map = JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+            ("age".to_owned(), JsonNode::Integer(42)),
+        ]);
+
+        map.extend(vec![
+            ("age".to_owned(), JsonNode::Integer(43)),
+            ("isMale".to_owned(), JsonNode::Boolean(true)),
+            ("height".to_owned(), JsonNode::Float(1.8)),
+        ]);
+
+        let expected = JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("John Doe".to_owned())),
+            ("age".to_owned(), JsonNode::Integer(43)),
+            ("isMale".to_owned(), JsonNode::Boolean(true)),
+            ("height".to_owned(), JsonNode::Float(1.8)),
+        ]);
+
+        assert_eq!(map, expected);
+    }
+
+    #[test]
+    fn entries_of_type_keeps_strings() {
+        use crate::JsonNode;
+
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "isMale": true,
+            "height": 1.8,
+            "nickname": "Jay",
+            "numbers": [1, 2, 3, 4, 5]
+        }"#;
+
+        let node = JsonNode::parse(json).unwrap();
+        let strings = node.as_object().unwrap().entries_of_type(JsonNode::is_string);
+
+        assert_eq!(strings, vec![
+            ("name", &JsonNode::String("Jason".to_owned())),
+            ("nickname", &JsonNode::String("Jay".to_owned())),
+        ]);
+    }
+
+    #[test]
+    fn with_capacity_preallocates() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let mut map = JsonPropertyMap::with_capacity(16);
+        assert!(map.capacity() >= 16);
+
+        map.add("number", JsonNode::Integer(42));
+        map.reserve(32);
+        assert!(map.capacity() >= 33);
+    }
+
+    #[test]
+    fn get_ignore_case_prefers_first_match() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let map = JsonPropertyMap::from([
+            ("userID".to_owned(), JsonNode::Integer(1)),
+            ("userId".to_owned(), JsonNode::Integer(2)),
+            ("Name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("Émile".to_owned(), JsonNode::Null),
+        ]);
+
+        assert_eq!(map.get_ignore_case("userid"), Some(&JsonNode::Integer(1)));
+        assert_eq!(map.get_ignore_case("userId"), Some(&JsonNode::Integer(1)));
+        assert_eq!(map.get_ignore_case("NAME"), Some(&JsonNode::String("Jason".to_owned())));
+        assert_eq!(map.get_ignore_case("émile"), None);
+        assert_eq!(map.get_ignore_case("age"), None);
+    }
+
+    #[test]
+    fn position_of_and_key_of() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let map = JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("age".to_owned(), JsonNode::Integer(30)),
+            ("nickname".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("spouse".to_owned(), JsonNode::Null),
+        ]);
+
+        assert_eq!(map.position_of("name"), Some(0));
+        assert_eq!(map.position_of("spouse"), Some(3));
+        assert_eq!(map.position_of("Name"), None);
+
+        assert_eq!(map.key_of(&JsonNode::String("Jason".to_owned())), Some("name"));
+        assert_eq!(map.key_of(&JsonNode::Null), Some("spouse"));
+        assert_eq!(map.key_of(&JsonNode::Float(30.0)), None);
+    }
+
+    #[test]
+    fn insert_at_position() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let mut map = JsonPropertyMap::from([
+            ("a".to_owned(), JsonNode::Integer(1)),
+            ("b".to_owned(), JsonNode::Integer(2)),
+            ("c".to_owned(), JsonNode::Integer(3)),
+        ]);
+
+        map.insert_at(1, "x".to_owned(), JsonNode::Null);
+        assert_eq!(map.property_names(), vec!["a", "x", "b", "c"]);
+
+        map.insert_at(4, "y".to_owned(), JsonNode::Null);
+        assert_eq!(map.property_names(), vec!["a", "x", "b", "c", "y"]);
+
+        map.insert_at(0, "c".to_owned(), JsonNode::Boolean(true));
+        assert_eq!(map.property_names(), vec!["c", "a", "x", "b", "y"]);
+        assert_eq!(map.get("c"), Some(&JsonNode::Boolean(true)));
+    }
+
+    #[test]
+    fn swap_remove_middle_key() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let mut map = JsonPropertyMap::from([
+            ("a".to_owned(), JsonNode::Integer(1)),
+            ("b".to_owned(), JsonNode::Integer(2)),
+            ("c".to_owned(), JsonNode::Integer(3)),
+            ("d".to_owned(), JsonNode::Integer(4)),
+        ]);
+
+        assert_eq!(map.swap_remove("b"), Some(JsonNode::Integer(2)));
+        assert_eq!(map.property_names(), vec!["a", "d", "c"]);
+        assert_eq!(map.swap_remove("b"), None);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn drain_empties_map() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let mut map = JsonPropertyMap::from([
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("age".to_owned(), JsonNode::Integer(30)),
+        ]);
+
+        let drained = map.drain().collect::<Vec<_>>();
+
+        assert_eq!(drained, vec![
+            ("name".to_owned(), JsonNode::String("Jason".to_owned())),
+            ("age".to_owned(), JsonNode::Integer(30)),
+        ]);
+        assert_eq!(map, JsonPropertyMap::new());
+
+        map.add("a", JsonNode::Null);
+        map.add("b", JsonNode::Null);
+        assert_eq!(map.drain().next(), Some(("a".to_owned(), JsonNode::Null)));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn from_pairs() {
+        use crate::{JsonNode, JsonPropertyMap};
+
+        let map = JsonPropertyMap::from_pairs([("age", 42), ("n", 7)]);
+
+        assert_eq!(map, JsonPropertyMap::from([
+            ("age".to_owned(), JsonNode::Integer(42)),
+            ("n".to_owned(), JsonNode::Integer(7)),
+        ]));
+
+        let map = JsonPropertyMap::from_pairs(vec![("a".to_owned(), "x"), ("b".to_owned(), "y"), ("a".to_owned(), "z")]);
+
+        assert_eq!(map.to_json_string(), r#"{"a":"z","b":"y"}"#);
+    }
+
+    #[test]
+    fn parsed_objects_use_the_property_map() {
+        use crate::{JsonNode, JsonNodeError, JsonPropertyMap};
+
+        let mut node = JsonNode::parse(r#"{"name": "Jason", "age": 30}"#).unwrap();
+        let map: &mut JsonPropertyMap = node.as_object_mut().unwrap();
+
+        map.add("isMale", JsonNode::Boolean(true));
+        map.add("age", JsonNode::Integer(31));
+
+        assert_eq!(map.get("age"), Some(&JsonNode::Integer(30)));
+        assert_eq!(map.remove("name"), Ok(JsonNode::String("Jason".to_owned())));
+        assert_eq!(map.remove("name"), Err(JsonNodeError::KeyNotFound("name".to_owned())));
+        assert_eq!(node.to_json_string(), r#"{"age":30,"isMale":true}"#);
+    }
 }
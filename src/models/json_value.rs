@@ -0,0 +1,39 @@
+use crate::models::JsonNode;
+
+/// A JSON value which isn't an object or an array, as returned by `JsonNode::as_scalar`.
+/// 
+/// The variants match the scalar variants of `JsonNode`, so every scalar can be handled in a single `match`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    String(String),
+    Integer(i64),
+
+    /// An integer too large for `i64` which still fits in a `u64`.
+    UInteger(u64),
+    Float(f64),
+    Boolean(bool),
+    Null,
+
+    /// A number kept exactly as it was written in the JSON.
+    RawNumber(String),
+
+    /// A value which is absent, as opposed to explicitly `null`.
+    #[cfg(feature = "undefined")]
+    Undefined,
+}
+
+impl From<JsonValue> for JsonNode {
+    fn from(value: JsonValue) -> Self {
+        match value {
+            JsonValue::String(value) => JsonNode::String(value),
+            JsonValue::Integer(value) => JsonNode::Integer(value),
+            JsonValue::UInteger(value) => JsonNode::UInteger(value),
+            JsonValue::Float(value) => JsonNode::Float(value),
+            JsonValue::Boolean(value) => JsonNode::Boolean(value),
+            JsonValue::Null => JsonNode::Null,
+            JsonValue::RawNumber(text) => JsonNode::RawNumber(text),
+            #[cfg(feature = "undefined")]
+            JsonValue::Undefined => JsonNode::Undefined,
+        }
+    }
+}
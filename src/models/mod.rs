@@ -1,5 +1,9 @@
+pub mod accessor;
 pub mod json_node;
 pub mod json_property_map;
+pub mod json_value;
 
+pub use accessor::*;
 pub use self::json_node::*;
-pub use json_property_map::*;
\ No newline at end of file
+pub use json_property_map::*;
+pub use json_value::*;
\ No newline at end of file
@@ -0,0 +1,364 @@
+use std::io::{BufRead, BufReader, Read};
+
+use crate::errors::JsonNodeError;
+use crate::models::JsonValue;
+use crate::parsing::{Expect, JsonNodeParser, ParseOptions, TokenKind, Tokenizer};
+use crate::Result;
+
+/// A step through a JSON document, as produced by `JsonEventReader`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    StartObject,
+    EndObject,
+    StartArray,
+    EndArray,
+
+    /// The name of the property whose value comes next.
+    Key(String),
+
+    /// A value which isn't an object or an array.
+    Value(JsonValue),
+}
+
+/// Reads a JSON document from a reader one event at a time, without ever holding more than a single token in memory.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{JsonEvent, JsonEventReader, JsonValue};
+/// 
+/// let events = JsonEventReader::new(r#"{"numbers": [1]}"#.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+/// 
+/// assert_eq!(events, vec![
+///     JsonEvent::StartObject,
+///     JsonEvent::Key("numbers".to_owned()),
+///     JsonEvent::StartArray,
+///     JsonEvent::Value(JsonValue::Integer(1)),
+///     JsonEvent::EndArray,
+///     JsonEvent::EndObject,
+/// ]);
+/// ```
+/// 
+/// # Remarks
+/// 
+/// The reader accepts exactly the documents `JsonNode::parse` accepts, but it can only report an error once it reaches it,
+/// so every event before the error has already been returned. Positions in errors are byte offsets from the start of the reader.
+/// A value missing between separators, like in `[1, ]`, is reported as `JsonNodeError::UnexpectedToken` since the text of the whole container isn't kept,
+/// and a key which isn't a string is reported as `JsonNodeError::InvalidKey` holding only its first token.
+/// A UTF-8 byte order mark at the very start of the reader is skipped.
+/// 
+/// # Errors
+/// 
+/// Each event is a `Result`, which holds `JsonNodeError::Io` if reading fails, `JsonNodeError::InvalidUtf8` if a token isn't UTF-8,
+/// or the error `JsonNode::parse` would give. Once an error is returned, the iterator ends.
+pub struct JsonEventReader<R: Read> {
+    reader: BufReader<R>,
+    position: usize,
+    // Whether each open container is an object.
+    open_containers: Vec<bool>,
+    expect: Expect,
+    is_empty: bool,
+    has_failed: bool,
+}
+
+impl<R: Read> JsonEventReader<R> {
+    /// Creates an event reader which reads the JSON from `reader`, buffering it internally.
+    pub fn new(reader: R) -> Self {
+        JsonEventReader {
+            reader: BufReader::new(reader),
+            position: 0,
+            open_containers: Vec::new(),
+            expect: Expect::Value,
+            is_empty: true,
+            has_failed: false,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn advance(&mut self, token: &mut Vec<u8>, byte: u8) {
+        self.reader.consume(1);
+        self.position += 1;
+        token.push(byte);
+    }
+
+    /// Reads the bytes of the next token and checks them with the tokenizer, returning where the token starts, its kind and its text.
+    fn next_token(&mut self) -> Result<Option<(usize, TokenKind, String)>> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.reader.consume(1);
+            self.position += 1;
+        }
+
+        let start = self.position;
+        let mut token = Vec::new();
+
+        match self.peek()? {
+            None => return Ok(None),
+            Some(byte @ (b'{' | b'}' | b'[' | b']' | b':' | b',')) => self.advance(&mut token, byte),
+            Some(b'"') => {
+                self.advance(&mut token, b'"');
+                let mut is_escaped = false;
+
+                loop {
+                    let byte = self.peek()?.ok_or(JsonNodeError::UnterminatedString { position: start })?;
+                    self.advance(&mut token, byte);
+
+                    match byte {
+                        _ if is_escaped => is_escaped = false,
+                        b'\\' => is_escaped = true,
+                        b'"' => break,
+                        _ => (),
+                    }
+                }
+            },
+            Some(byte) => {
+                self.advance(&mut token, byte);
+
+                // Read the rest of a number or a literal, or the rest of a multi-byte character so it can be reported.
+                while let Some(byte) = self.peek()? {
+                    match byte {
+                        b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'+' | b'-' | b'.' if token[0].is_ascii_alphanumeric() || token[0] == b'-' => (),
+                        0x80..=0xBF if !token[0].is_ascii() => (),
+                        _ => break,
+                    }
+
+                    self.advance(&mut token, byte);
+                }
+            },
+        }
+
+        let text = String::from_utf8(token).map_err(|error| JsonNodeError::InvalidUtf8 { position: start + error.utf8_error().valid_up_to() })?;
+
+        // The byte order mark is read like any other character, so it's found even when the reader returns fewer bytes than it has.
+        if start == 0 && text == "\u{FEFF}" {
+            return self.next_token();
+        }
+
+        match Tokenizer::with_options(&text, &ParseOptions::default()).next() {
+            Some(Ok(raw)) if raw.text.len() == text.len() => Ok(Some((start, raw.kind, text))),
+            Some(Err(error)) => Err(offset_error(error, start)),
+            _ => Err(JsonNodeError::UnexpectedToken { position: start, token: text }),
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<JsonEvent>> {
+        loop {
+            let is_key_expected = matches!(self.expect, Expect::Key | Expect::KeyOrBraceClose);
+
+            let token = match self.next_token() {
+                Err(JsonNodeError::UnexpectedToken { token, .. }) if is_key_expected => return Err(JsonNodeError::InvalidKey(token)),
+                token => token?,
+            };
+
+            let Some((start, kind, text)) = token else {
+                return match self.expect {
+                    Expect::End => Ok(None),
+                    _ if self.is_empty => Err(JsonNodeError::EmptyJson(None)),
+                    _ => Err(JsonNodeError::UnexpectedEndOfInput { position: self.position }),
+                };
+            };
+
+            self.is_empty = false;
+            let is_in_object = self.open_containers.last() == Some(&true);
+
+            let event = match (self.expect, kind) {
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::String) => JsonEvent::Value(JsonValue::String(JsonNodeParser::unescape(&text))),
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::Number) => {
+                    JsonEvent::Value(JsonNodeParser::number(&text).as_scalar().expect("a number is always a scalar"))
+                },
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::Boolean) => JsonEvent::Value(JsonValue::Boolean(text == "true")),
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::Null) => JsonEvent::Value(JsonValue::Null),
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::BraceOpen) => {
+                    self.open_containers.push(true);
+                    self.expect = Expect::KeyOrBraceClose;
+                    return Ok(Some(JsonEvent::StartObject));
+                },
+                (Expect::Value | Expect::ValueOrBracketClose, TokenKind::BracketOpen) => {
+                    self.open_containers.push(false);
+                    self.expect = Expect::ValueOrBracketClose;
+                    return Ok(Some(JsonEvent::StartArray));
+                },
+                (Expect::ValueOrBracketClose, TokenKind::BracketClose) | (Expect::CommaOrClose, TokenKind::BracketClose) if !is_in_object => {
+                    self.open_containers.pop();
+                    JsonEvent::EndArray
+                },
+                (Expect::KeyOrBraceClose, TokenKind::BraceClose) | (Expect::CommaOrClose, TokenKind::BraceClose) if is_in_object => {
+                    self.open_containers.pop();
+                    JsonEvent::EndObject
+                },
+                (Expect::Key | Expect::KeyOrBraceClose, TokenKind::String) => {
+                    self.expect = Expect::Colon;
+                    return Ok(Some(JsonEvent::Key(JsonNodeParser::unescape(&text))));
+                },
+                (_, TokenKind::Colon | TokenKind::Comma) if is_key_expected => return Err(JsonNodeError::InvalidKey(String::new())),
+                (_, kind) if is_key_expected && kind != TokenKind::BraceClose => return Err(JsonNodeError::InvalidKey(text)),
+                (Expect::Colon, TokenKind::Colon) => {
+                    self.expect = Expect::Value;
+                    continue;
+                },
+                (Expect::CommaOrClose, TokenKind::Comma) => {
+                    self.expect = if is_in_object { Expect::Key } else { Expect::Value };
+                    continue;
+                },
+                _ => return Err(JsonNodeError::UnexpectedToken { position: start, token: text }),
+            };
+
+            // The event completed a value.
+            self.expect = if self.open_containers.is_empty() { Expect::End } else { Expect::CommaOrClose };
+
+            return Ok(Some(event));
+        }
+    }
+}
+
+impl<R: Read> Iterator for JsonEventReader<R> {
+    type Item = Result<JsonEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.has_failed {
+            return None;
+        }
+
+        let event = self.next_event();
+        self.has_failed = event.is_err();
+
+        event.transpose()
+    }
+}
+
+/// Moves the position of a tokenizer error, which is relative to the start of a token, to be relative to the start of the reader.
+fn offset_error(error: JsonNodeError, start: usize) -> JsonNodeError {
+    match error {
+        JsonNodeError::UnexpectedCharacter { position, character } => JsonNodeError::UnexpectedCharacter { position: start + position, character },
+        JsonNodeError::UnexpectedToken { position, token } => JsonNodeError::UnexpectedToken { position: start + position, token },
+        JsonNodeError::UnexpectedEndOfInput { position } => JsonNodeError::UnexpectedEndOfInput { position: start + position },
+        JsonNodeError::UnterminatedString { position } => JsonNodeError::UnterminatedString { position: start + position },
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use super::{JsonEvent, JsonEventReader};
+    use crate::{JsonNode, JsonNodeError, JsonValue};
+
+    fn events(json: &str) -> Vec<Result<JsonEvent, JsonNodeError>> {
+        JsonEventReader::new(json.as_bytes()).collect()
+    }
+
+    /// A reader which returns a single byte on every read.
+    struct ByteByByte<'a>(&'a [u8]);
+
+    impl Read for ByteByByte<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buffer.first_mut()) {
+                (Some((byte, rest)), Some(first)) => {
+                    *first = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                },
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn read_sample_events() {
+        let json = r#"
+        {
+            "name": "Jason",
+            "age": 30,
+            "height": 1.8,
+            "numbers": [1, 2],
+            "children": [
+                {"name": "Jasmine\n", "isMale": false, "spouse": null}
+            ],
+            "empty": {}
+        }"#;
+
+        let expected = vec![
+            JsonEvent::StartObject,
+            JsonEvent::Key("name".to_owned()),
+            JsonEvent::Value(JsonValue::String("Jason".to_owned())),
+            JsonEvent::Key("age".to_owned()),
+            JsonEvent::Value(JsonValue::Integer(30)),
+            JsonEvent::Key("height".to_owned()),
+            JsonEvent::Value(JsonValue::Float(1.8)),
+            JsonEvent::Key("numbers".to_owned()),
+            JsonEvent::StartArray,
+            JsonEvent::Value(JsonValue::Integer(1)),
+            JsonEvent::Value(JsonValue::Integer(2)),
+            JsonEvent::EndArray,
+            JsonEvent::Key("children".to_owned()),
+            JsonEvent::StartArray,
+            JsonEvent::StartObject,
+            JsonEvent::Key("name".to_owned()),
+            JsonEvent::Value(JsonValue::String("Jasmine\n".to_owned())),
+            JsonEvent::Key("isMale".to_owned()),
+            JsonEvent::Value(JsonValue::Boolean(false)),
+            JsonEvent::Key("spouse".to_owned()),
+            JsonEvent::Value(JsonValue::Null),
+            JsonEvent::EndObject,
+            JsonEvent::EndArray,
+            JsonEvent::Key("empty".to_owned()),
+            JsonEvent::StartObject,
+            JsonEvent::EndObject,
+            JsonEvent::EndObject,
+        ];
+
+        assert_eq!(events(json), expected.into_iter().map(Ok).collect::<Vec<_>>());
+        assert_eq!(events("\u{FEFF} 18446744073709551615 "), vec![Ok(JsonEvent::Value(JsonValue::UInteger(u64::MAX)))]);
+    }
+
+    #[test]
+    fn read_errors_mid_stream() {
+        assert_eq!(events("[1, 2 3]"), vec![
+            Ok(JsonEvent::StartArray),
+            Ok(JsonEvent::Value(JsonValue::Integer(1))),
+            Ok(JsonEvent::Value(JsonValue::Integer(2))),
+            Err(JsonNodeError::UnexpectedToken { position: 6, token: "3".to_owned() }),
+        ]);
+
+        assert_eq!(events(r#"{"a": "\x"}"#).last(), Some(&Err(JsonNodeError::UnexpectedCharacter { position: 8, character: 'x' })));
+        assert_eq!(events(r#"{"a": tru}"#).last(), Some(&Err(JsonNodeError::UnexpectedToken { position: 6, token: "tru".to_owned() })));
+        assert_eq!(events("[1] é").last(), Some(&Err(JsonNodeError::UnexpectedCharacter { position: 4, character: 'é' })));
+        assert_eq!(events("[1, ]").last(), Some(&Err(JsonNodeError::UnexpectedToken { position: 4, token: "]".to_owned() })));
+
+        let invalid_utf8 = JsonEventReader::new(&b"[\"\xFF\"]"[..]).last();
+        assert_eq!(invalid_utf8, Some(Err(JsonNodeError::InvalidUtf8 { position: 2 })));
+
+        // Everything else fails exactly like parsing does.
+        for json in ["", "  ", "[1, 2", "[1, 2}", r#"{"a" 1}"#, "01", r#""abc"#, "{foo: 1}", "{1: 2}", "{,}"] {
+            assert_eq!(events(json).into_iter().find_map(Result::err), JsonNode::parse(json).err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn read_integers_like_parsing() {
+        for json in ["[18446744073709551616]", "[-9223372036854775809]", r#"{"id": 100000000000000000000}"#] {
+            assert!(JsonNode::parse(json).is_err(), "{}", json);
+            assert_eq!(events(json).into_iter().find_map(Result::err), JsonNode::parse(json).err(), "{}", json);
+        }
+
+        assert_eq!(events("[18446744073709551615, 1.8446744073709552e19]"), vec![
+            Ok(JsonEvent::StartArray),
+            Ok(JsonEvent::Value(JsonValue::UInteger(u64::MAX))),
+            Ok(JsonEvent::Value(JsonValue::Float(1.8446744073709552e19))),
+            Ok(JsonEvent::EndArray),
+        ]);
+    }
+
+    #[test]
+    fn read_byte_by_byte() {
+        for json in ["\u{FEFF}[1, {\"é\": \"\u{FEFF}\"}]", "\u{FEFF}", " \u{FEFF}1", "[1] é", r#"{"a": 1.5e3}"#] {
+            let events_byte_by_byte: Vec<_> = JsonEventReader::new(ByteByByte(json.as_bytes())).collect();
+
+            assert_eq!(events_byte_by_byte, events(json), "{}", json);
+            assert_eq!(events_byte_by_byte.into_iter().find_map(Result::err), JsonNode::parse(json).err(), "{}", json);
+        }
+    }
+}
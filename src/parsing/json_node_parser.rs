@@ -1,261 +1,378 @@
-use crate::{models::JsonNode, errors::JsonNodeError, models::JsonPropertyMap, parsing::tokens};
+use crate::{models::JsonNode, errors::JsonNodeError, models::JsonPropertyMap, parsing::tokens, parsing::ParseOptions};
+use crate::parsing::{is_lossy, scan, scan_value, LossyNumber, RawToken, SpanRecorder, SpanTable, TokenKind, Tokenizer};
 
 pub struct JsonNodeParser;
 
 impl JsonNodeParser {
-    pub fn parse_node(json_node_as_json_string: &str, parent_node: Option<Box<String>>) -> Result<JsonNode, JsonNodeError> {
-        let trim = json_node_as_json_string.trim();
+    /// Parses a JSON document into a node tree, checking its structure with the same scanner `validate` uses.
+    pub fn parse(json: &str, options: &ParseOptions) -> Result<JsonNode, JsonNodeError> {
+        let mut builder = TreeBuilder::new(options);
 
-        if trim.is_empty() {
-            return Err(JsonNodeError::EmptyJson(parent_node));
-        }
+        scan(Tokenizer::with_options(json, options), |token| builder.push(token))?;
 
-        if let Some(node) = Self::parse_value(json_node_as_json_string) {
-            return Ok(node);
-        }
+        Ok(builder.root.expect("a successful scan always contains a value"))
+    }
 
-        if let Some(node) = Self::parse_array(json_node_as_json_string) {
-            return Ok(node);
-        }
-        
-        if let Some(node) = Self::parse_object(json_node_as_json_string) {
-            return Ok(node);
-        }
+    /// Parses a JSON document into a node tree while recording where every value was written.
+    /// A leading byte order mark is skipped here, so that the spans are counted from the very start of `json`.
+    pub fn parse_with_spans(json: &str, options: &ParseOptions) -> Result<(JsonNode, SpanTable), JsonNodeError> {
+        let start = json.len() - Self::strip_byte_order_mark(json).len();
+        let mut builder = TreeBuilder::new(options);
+        let mut recorder = SpanRecorder::default();
+
+        scan(Tokenizer::with_options(json, options).starting_at(start), |token| {
+            builder.push(token);
+            recorder.push(token);
+        })?;
 
-        Err(JsonNodeError::CouldntParseNode(json_node_as_json_string.to_string()))
+        Ok((builder.root.expect("a successful scan always contains a value"), recorder.table))
     }
 
-    fn parse_value(json: &str) -> Option<JsonNode> {
-        if let Some(node) = Self::parse_string(json) {
-            return Some(node);
-        }
+    /// Parses a JSON document into a node tree while finding every number which doesn't fit exactly in the node it was parsed into.
+    /// The numbers are found through their spans and are listed in the order they were written.
+    pub fn parse_with_diagnostics(json: &str, options: &ParseOptions) -> Result<(JsonNode, Vec<LossyNumber>), JsonNodeError> {
+        let (node, spans) = Self::parse_with_spans(json, options)?;
 
-        if let Some(node) = Self::parse_integer(json) {
-            return Some(node);
-        }
+        let mut numbers = spans
+            .iter()
+            .filter(|(_, span)| json[span.start..].starts_with(|c: char| c == '-' || c.is_ascii_digit()))
+            .filter(|(_, span)| !(options.preserve_number_text && Self::is_json_number(&json[span.clone()])) && is_lossy(&json[span.clone()]))
+            .collect::<Vec<_>>();
 
-        if let Some(node) = Self::parse_float(json) {
-            return Some(node);
-        }
+        numbers.sort_by_key(|(_, span)| span.start);
 
-        if let Some(node) = Self::parse_boolean(json) {
-            return Some(node);
-        }
-
-        if let Some(node) = Self::parse_null(json) {
-            return Some(node);
-        }
+        let lossy = numbers
+            .into_iter()
+            .map(|(pointer, span)| LossyNumber { pointer: pointer.to_owned(), text: json[span].to_owned() })
+            .collect();
 
-        None
+        Ok((node, lossy))
     }
 
-    fn parse_string(value: &str) -> Option<JsonNode> {
-        let trim = value.trim();
-        
-        if trim.is_empty() {
-            return None;
-        }
+    /// Parses consecutive JSON documents separated by white space, like newline-delimited JSON.
+    pub fn parse_many(json: &str, options: &ParseOptions) -> Result<Vec<JsonNode>, JsonNodeError> {
+        let mut tokenizer = Tokenizer::with_options(json, options);
+        let mut documents = Vec::new();
 
-        if trim.starts_with(tokens::DOUBLE_QUOTE) && trim.ends_with(tokens::DOUBLE_QUOTE) {
-            let text = trim[1..trim.len() - 1].to_owned();
-            return Some(JsonNode::String(text));
+        while !tokenizer.is_at_end() {
+            let mut builder = TreeBuilder::new(options);
+
+            scan_value(&mut tokenizer, &mut |token| builder.push(token)).map_err(|error| JsonNodeError::InDocument {
+                index: documents.len(),
+                error: Box::new(error),
+            })?;
+
+            documents.push(builder.root.expect("a successful scan always contains a value"));
         }
 
-        None
+        Ok(documents)
     }
 
-    fn parse_integer(value: &str) -> Option<JsonNode> {
-        let trim = value.trim();
+    /// Removes a byte order mark from the very start of the JSON. Byte order marks anywhere else are left untouched.
+    pub fn strip_byte_order_mark(json: &str) -> &str {
+        json.strip_prefix(tokens::BYTE_ORDER_MARK).unwrap_or(json)
+    }
 
-        if trim.is_empty() {
-            return None;
-        }
+    /// Decodes the escapes in a string token, whose text still includes its quotes.
+    /// The tokenizer has already checked the escapes, and `\u` escapes which aren't a valid UTF-16 sequence become U+FFFD.
+    pub(crate) fn unescape(token_text: &str) -> String {
+        let inner = &token_text[1..token_text.len() - 1];
 
-        match trim.parse::<i64>() {
-            Ok(num) => Some(JsonNode::Integer(num)),
-            Err(_) => None,
+        if !inner.contains('\\') {
+            return inner.to_owned();
         }
-    }
 
-    fn parse_float(value: &str) -> Option<JsonNode> {
-        let trim = value.trim();
+        let mut unescaped = String::with_capacity(inner.len());
+        let mut characters = inner.chars();
 
-        if trim.is_empty() {
-            return None;
-        }
+        while let Some(character) = characters.next() {
+            if character != '\\' {
+                unescaped.push(character);
+                continue;
+            }
 
-        match trim.parse::<f64>() {
-            Ok(num) => Some(JsonNode::Float(num)),
-            Err(_) => None,
+            match characters.next() {
+                Some('b') => unescaped.push('\u{8}'),
+                Some('f') => unescaped.push('\u{C}'),
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('t') => unescaped.push('\t'),
+                Some('u') => {
+                    let unit = Self::read_utf16_unit(&mut characters);
+
+                    let character = match unit {
+                        0xD800..=0xDBFF => {
+                            // A high surrogate must be followed by an escaped low surrogate.
+                            let mut lookahead = characters.clone();
+
+                            match (lookahead.next(), lookahead.next()) {
+                                (Some('\\'), Some('u')) => {
+                                    let low = Self::read_utf16_unit(&mut lookahead);
+
+                                    if (0xDC00..=0xDFFF).contains(&low) {
+                                        characters = lookahead;
+                                        char::from_u32(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00))
+                                    } else {
+                                        None
+                                    }
+                                },
+                                _ => None,
+                            }
+                        },
+                        _ => char::from_u32(unit),
+                    };
+
+                    unescaped.push(character.unwrap_or(char::REPLACEMENT_CHARACTER));
+                },
+                Some(escaped) => unescaped.push(escaped),
+                None => unescaped.push('\\'),
+            }
         }
-    }
 
-    fn parse_boolean(value: &str) -> Option<JsonNode> {
-        let trim = value.trim();
+        unescaped
+    }
 
-        if trim.is_empty() {
-            return None;
+    /// Gets the name of an object key from its token, which is a string token or an unquoted word.
+    pub(crate) fn key_text(token_text: &str) -> String {
+        match token_text.starts_with(tokens::DOUBLE_QUOTE) {
+            true => Self::unescape(token_text),
+            false => token_text.to_owned(),
         }
+    }
+
+    fn read_utf16_unit(characters: &mut std::str::Chars) -> u32 {
+        characters
+            .take(4)
+            .fold(0, |unit, digit| unit * 16 + digit.to_digit(16).unwrap_or(0))
+    }
 
-        if trim.eq_ignore_ascii_case(tokens::TRUE) {
-            return Some(JsonNode::Boolean(true));
+    /// Converts a number token into the smallest fitting node, trying `i64`, then `u64` and finally `f64`.
+    pub(crate) fn number(text: &str) -> JsonNode {
+        if let Ok(integer) = text.parse::<i64>() {
+            return JsonNode::Integer(integer);
         }
 
-        if trim.eq_ignore_ascii_case(tokens::FALSE) {
-            return Some(JsonNode::Boolean(false));
+        if let Ok(integer) = text.parse::<u64>() {
+            return JsonNode::UInteger(integer);
         }
 
-        None
+        JsonNode::Float(text.parse().expect("the tokenizer only accepts numbers which parse as a float"))
     }
 
-    fn parse_null(value: &str) -> Option<JsonNode> {
-        let trim = value.trim();
+    /// Checks that a number follows the JSON grammar: `-? (0 | [1-9][0-9]*) (.[0-9]+)? ([eE][+-]?[0-9]+)?`.
+    pub(crate) fn is_json_number(value: &str) -> bool {
+        let bytes = value.as_bytes();
+        let mut index = 0;
+
+        let count_digits = |start: usize| bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
 
-        if trim.is_empty() {
-            return None;
+        if bytes.get(index) == Some(&b'-') {
+            index += 1;
         }
 
-        if trim.eq_ignore_ascii_case(tokens::NULL) {
-            return Some(JsonNode::Null);
+        match bytes.get(index) {
+            Some(b'0') => index += 1,
+            Some(b'1'..=b'9') => index += count_digits(index),
+            _ => return false,
         }
 
-        None
-    }
+        if bytes.get(index) == Some(&b'.') {
+            let digits = count_digits(index + 1);
 
-    fn parse_array(array: &str) -> Option<JsonNode> {
-        let trim = array.trim();
+            if digits == 0 {
+                return false;
+            }
 
-        if trim.is_empty() {
-            return None;
+            index += 1 + digits;
         }
 
-        if trim.starts_with(tokens::LEFT_BRACKET) && trim.ends_with(tokens::RIGHT_BRACKET) {
-            let no_brackets = trim[1..trim.len() - 1].trim();
-            
-            if no_brackets.replace(" ", "").replace("\t", "").is_empty() {
-                return Some(JsonNode::Array(Vec::new()));
-            }
+        if matches!(bytes.get(index), Some(b'e') | Some(b'E')) {
+            index += 1;
 
-            let mut elements = Vec::new();
-
-            let mut element = String::new();
-            let mut level = 0;
-
-            for char in no_brackets.chars() {
-                if char == tokens::LEFT_BRACE || char == tokens::LEFT_BRACKET {
-                    element += &char.to_string();
-                    level += 1;
-                } else if char == tokens::RIGHT_BRACE || char == tokens::RIGHT_BRACKET {
-                    element += &char.to_string();
-                    level -= 1;
-                } else if char == tokens::COMMA && level == 0 {
-                    elements.push(element.trim().to_owned());
-                    element = String::new();
-                } else {
-                    element += &char.to_string();
-                }
+            if matches!(bytes.get(index), Some(b'+') | Some(b'-')) {
+                index += 1;
             }
 
-            elements.push(element.trim().to_owned());
-
-            let elements = elements.iter()
-                .map(|value| value.trim())
-                .map(|value| {
-                    Self::parse_node(value, Some(Box::new(array.to_string()))).ok()
-                })
-                .collect::<Vec<Option<JsonNode>>>();
+            let digits = count_digits(index);
 
-            let mut array = Vec::new();
-
-            for e in elements.into_iter() {
-                match e {
-                    Some(node) => array.push(node),
-                    None => return None,
-                }
+            if digits == 0 {
+                return false;
             }
 
-            return Some(JsonNode::Array(array));
+            index += digits;
         }
 
-        None
+        index == bytes.len()
     }
+}
 
-    fn parse_object(object: &str) -> Option<JsonNode> {
-        let trim = object.trim();
-
-        if trim.is_empty() {
-            return None;
-        }
+/// An object or array which has been opened but not yet closed.
+enum OpenContainer {
+    Object(Vec<(String, JsonNode)>),
+    Array(Vec<JsonNode>),
+}
 
-        if trim.starts_with(tokens::LEFT_BRACE) && trim.ends_with(tokens::RIGHT_BRACE) {
-            let no_braces = trim[1..trim.len() - 1].trim();
-            
-            if no_braces.replace(" ", "").replace("\t", "").is_empty() {
-                return Some(JsonNode::Object(JsonPropertyMap::new()));
-            }
+/// Builds a node tree from the tokens of a document which the scanner has already checked.
+struct TreeBuilder<'o> {
+    options: &'o ParseOptions,
+    // Every open container together with the key it will be inserted under in its parent object.
+    open_containers: Vec<(Option<String>, OpenContainer)>,
+    key: Option<String>,
+    root: Option<JsonNode>,
+}
 
-            let mut properties = Vec::new();
-
-            let mut property = String::new();
-            let mut level = 0;
-
-            for char in no_braces.chars() {
-                if char == tokens::LEFT_BRACE || char == tokens::LEFT_BRACKET {
-                    property += &char.to_string();
-                    level += 1;
-                } else if char == tokens::RIGHT_BRACE || char == tokens::RIGHT_BRACKET {
-                    property += &char.to_string();
-                    level -= 1;
-                } else if char == tokens::COMMA && level == 0 {
-                    properties.push(property.trim().to_owned());
-                    property = String::new();
-                } else {
-                    property += &char.to_string();
-                }
-            }
+impl<'o> TreeBuilder<'o> {
+    fn new(options: &'o ParseOptions) -> Self {
+        TreeBuilder { options, open_containers: Vec::new(), key: None, root: None }
+    }
 
-            properties.push(property.trim().to_owned());
+    fn push(&mut self, token: &RawToken) {
+        let is_key = matches!(self.open_containers.last(), Some((_, OpenContainer::Object(_))))
+            && self.key.is_none()
+            && token.kind == TokenKind::String;
 
-            let kvps = properties.iter()
-                .map(|property| property.trim())
-                .map(|property| {
-                    let (mut key, value) = property.split_once(tokens::COLON).unwrap();
+        if is_key {
+            self.key = Some(JsonNodeParser::key_text(token.text));
+            return;
+        }
 
-                    key = &key[1..key.len() - 1];
-                    (key.to_owned(), Self::parse_node(value, Some(Box::new(object.to_string()))).ok())
-                })
-                .collect::<Vec<(String, Option<JsonNode>)>>();
+        let node = match token.kind {
+            TokenKind::BraceOpen => {
+                self.open_containers.push((self.key.take(), OpenContainer::Object(Vec::new())));
+                return;
+            },
+            TokenKind::BracketOpen => {
+                self.open_containers.push((self.key.take(), OpenContainer::Array(Vec::new())));
+                return;
+            },
+            TokenKind::BraceClose | TokenKind::BracketClose => {
+                let (key, container) = self.open_containers.pop().expect("the scanner only accepts balanced closing tokens");
+                self.key = key;
 
-            let objects = kvps.iter().map(|(k, p)| (k.clone(), p.clone().unwrap())).collect::<Vec<(String, JsonNode)>>();
+                match container {
+                    OpenContainer::Object(properties) => JsonNode::Object(JsonPropertyMap::from_iter(properties)),
+                    OpenContainer::Array(elements) => JsonNode::Array(elements),
+                }
+            },
+            TokenKind::Colon | TokenKind::Comma => return,
+            TokenKind::String => JsonNode::String(JsonNodeParser::unescape(token.text)),
+            TokenKind::Number => self.number(token.text),
+            TokenKind::Boolean => JsonNode::Boolean(token.text.eq_ignore_ascii_case(tokens::TRUE)),
+            TokenKind::Null => JsonNode::Null,
+        };
+
+        match self.open_containers.last_mut() {
+            Some((_, OpenContainer::Object(properties))) => {
+                properties.push((self.key.take().expect("the scanner only accepts values after a key"), node));
+            },
+            Some((_, OpenContainer::Array(elements))) => elements.push(node),
+            None => self.root = Some(node),
+        }
+    }
 
-            return Some(JsonNode::Object(JsonPropertyMap::from_iter(objects)));
+    fn number(&self, text: &str) -> JsonNode {
+        // Lenient numbers like `01` and `.5` aren't valid JSON, so writing their text back would produce invalid JSON.
+        if self.options.preserve_number_text && JsonNodeParser::is_json_number(text) {
+            return JsonNode::RawNumber(text.to_owned());
         }
 
-        None
+        JsonNodeParser::number(text)
     }
 }
 
-
-
-
 #[cfg(test)]
 mod tests {
     use std::{collections::HashMap, vec};
     use crate::models::*;
+    use crate::parsing::ParseOptions;
+    use crate::JsonNodeError;
 
     #[test]
     fn parse_string() {
         let json_string = "\"text\"";
 
-        let json_node = JsonNode::parse(&json_string).unwrap();
+        let json_node = JsonNode::parse(json_string).unwrap();
         assert_eq!(json_node, JsonNode::String("text".to_owned()));
     }
 
+    #[test]
+    fn parse_string_escapes() {
+        let json_node = JsonNode::parse(r#""a\"b\\c\/d\n\té😀\ud800""#).unwrap();
+        assert_eq!(json_node, JsonNode::String("a\"b\\c/d\n\té\u{1F600}\u{FFFD}".to_owned()));
+
+        let json_node = JsonNode::parse(r#"{"key\"with quote": "line\nbreak"}"#).unwrap();
+        assert_eq!(json_node.to_json_string(), r#"{"key\"with quote":"line\nbreak"}"#);
+    }
+
+    #[test]
+    fn parse_with_spans_points_into_source() {
+        let json = "\u{FEFF}{\n  \"name\": \"Jason\",\n  \"a/b~\": true,\n  \"children\": [\n    {\"name\": \"Jason Jr.\", \"age\": 5}\n  ]\n}";
+        let (node, spans) = JsonNode::parse_with_spans(json).unwrap();
+
+        assert_eq!(node, JsonNode::parse(json).unwrap());
+        assert_eq!(&json[spans.get("/children/0/name").unwrap()], "\"Jason Jr.\"");
+        assert_eq!(&json[spans.get("/children/0").unwrap()], r#"{"name": "Jason Jr.", "age": 5}"#);
+        assert_eq!(&json[spans.get("/a~1b~0").unwrap()], "true");
+        assert_eq!(spans.get(""), Some(3..json.len()));
+        assert_eq!(spans.get("/children/1"), None);
+        assert_eq!(spans.len(), 7);
+    }
+
+    #[test]
+    fn parse_keeps_white_space_inside_strings() {
+        // Only the white space between tokens is skipped, never the contents of a string.
+        assert_eq!(JsonNode::parse(r#"  "  spaced  "  "#).unwrap(), JsonNode::String("  spaced  ".to_owned()));
+        assert_eq!(JsonNode::parse(r#"" \t{ [ \n ""#).unwrap(), JsonNode::String(" \t{ [ \n ".to_owned()));
+
+        let json_node = JsonNode::parse(r#"{ "  key  " : [ "  a", "b  ", " " ] }"#).unwrap();
+
+        let expected = JsonNode::Object(JsonPropertyMap::from([(
+            "  key  ".to_owned(),
+            JsonNode::Array(vec![
+                JsonNode::String("  a".to_owned()),
+                JsonNode::String("b  ".to_owned()),
+                JsonNode::String(" ".to_owned()),
+            ]),
+        )]));
+
+        assert_eq!(json_node, expected);
+        assert_eq!(json_node.to_json_string(), r#"{"  key  ":["  a","b  "," "]}"#);
+    }
+
+    #[test]
+    fn parse_many_documents() {
+        let documents = JsonNode::parse_many("{\"a\":1}\n{\"b\":2}\n3").unwrap();
+
+        assert_eq!(documents, vec![
+            JsonNode::Object(JsonPropertyMap::from([("a".to_owned(), JsonNode::Integer(1))])),
+            JsonNode::Object(JsonPropertyMap::from([("b".to_owned(), JsonNode::Integer(2))])),
+            JsonNode::Integer(3),
+        ]);
+
+        assert_eq!(JsonNode::parse_many("[1][2] \"three\"").unwrap().len(), 3);
+        assert_eq!(JsonNode::parse_many(" \n ").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn parse_many_reports_failing_document() {
+        let error = JsonNode::parse_many("{\"a\":1}\n{\"b\":}\n3").unwrap_err();
+
+        assert_eq!(error, JsonNodeError::InDocument {
+            index: 1,
+            error: Box::new(JsonNodeError::EmptyJson(Some(Box::new("{\"b\":}".to_owned())))),
+        });
+
+        assert_eq!(
+            JsonNode::parse_many("1\n[2").unwrap_err().to_string(),
+            "document 1: unexpected end of input at position 4"
+        );
+    }
+
     #[test]
     fn parse_integer() {
         let json_integer = "123";
 
-        let json_node = JsonNode::parse(&json_integer).unwrap();
+        let json_node = JsonNode::parse(json_integer).unwrap();
         assert_eq!(json_node, JsonNode::Integer(123));
     }
 
@@ -263,15 +380,186 @@ mod tests {
     fn parse_float() {
         let json_float = "123.456";
 
-        let json_node = JsonNode::parse(&json_float).unwrap();
+        let json_node = JsonNode::parse(json_float).unwrap();
         assert_eq!(json_node, JsonNode::Float(123.456));
     }
 
+    #[test]
+    fn parse_empty_top_level() {
+        assert_eq!(JsonNode::parse(""), Err(JsonNodeError::EmptyJson(None)));
+        assert_eq!(JsonNode::parse(" \n\t "), Err(JsonNodeError::EmptyJson(None)));
+    }
+
+    #[test]
+    fn parse_empty_nested_value() {
+        assert_eq!(JsonNode::parse("[,]"), Err(JsonNodeError::EmptyJson(Some(Box::new("[,]".to_owned())))));
+        assert_eq!(JsonNode::parse("[1, ]"), Err(JsonNodeError::EmptyJson(Some(Box::new("[1, ]".to_owned())))));
+        assert_eq!(JsonNode::parse(r#"{"a":}"#), Err(JsonNodeError::EmptyJson(Some(Box::new(r#"{"a":}"#.to_owned())))));
+    }
+
+    #[test]
+    fn parse_number_grammar() {
+        assert_eq!(JsonNode::parse("0").unwrap(), JsonNode::Integer(0));
+        assert_eq!(JsonNode::parse("-0.5").unwrap(), JsonNode::Float(-0.5));
+        assert_eq!(JsonNode::parse("1E+2").unwrap(), JsonNode::Float(100.0));
+        assert_eq!(JsonNode::parse("2.5e-3").unwrap(), JsonNode::Float(0.0025));
+    }
+
+    #[test]
+    fn parse_strict_numbers_rejects_invalid_grammar() {
+        for json in ["01", ".5", "5.", "-", "+1", "1e", "NaN"] {
+            assert!(JsonNode::parse(json).is_err(), "{} should not parse", json);
+        }
+    }
+
+    #[test]
+    fn parse_lenient_numbers() {
+        let options = ParseOptions {
+            lenient_numbers: true,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(JsonNode::parse_with_options("01", &options).unwrap(), JsonNode::Integer(1));
+        assert_eq!(JsonNode::parse_with_options(".5", &options).unwrap(), JsonNode::Float(0.5));
+        assert_eq!(JsonNode::parse_with_options("5.", &options).unwrap(), JsonNode::Float(5.0));
+    }
+
+    #[test]
+    fn parse_unquoted_key_is_invalid() {
+        assert_eq!(JsonNode::parse("{foo: 1}"), Err(JsonNodeError::InvalidKey("foo".to_owned())));
+        assert_eq!(JsonNode::parse(r#"{"foo: 1}"#), Err(JsonNodeError::UnterminatedString { position: 1 }));
+    }
+
+    #[test]
+    fn parse_unquoted_key_leniently() {
+        let options = ParseOptions {
+            allow_unquoted_keys: true,
+            ..ParseOptions::default()
+        };
+
+        let json_node = JsonNode::parse_with_options("{foo: 1}", &options).unwrap();
+        assert_eq!(json_node, JsonNode::Object(JsonPropertyMap::from([
+            ("foo".to_owned(), JsonNode::Integer(1)),
+        ])));
+    }
+
+    #[test]
+    fn parse_preserving_number_text() {
+        let options = ParseOptions {
+            preserve_number_text: true,
+            ..ParseOptions::default()
+        };
+
+        for json in ["1.10", "1e3", "100.00", "-0", "[1.10,1e3,100.00]"] {
+            let json_node = JsonNode::parse_with_options(json, &options).unwrap();
+            assert_eq!(json_node.to_json_string(), json);
+        }
+
+        let json_node = JsonNode::parse_with_options("100.00", &options).unwrap();
+        assert_eq!(json_node, JsonNode::RawNumber("100.00".to_owned()));
+        assert_eq!(json_node.as_f64(), Some(100.0));
+    }
+
+    #[test]
+    fn parse_lenient_numbers_preserving_number_text() {
+        let options = ParseOptions {
+            lenient_numbers: true,
+            preserve_number_text: true,
+            ..ParseOptions::default()
+        };
+
+        let json_node = JsonNode::parse_with_options("[01, +1, .5, 1., -.5e1, 1.10]", &options).unwrap();
+        assert_eq!(json_node, JsonNode::Array(vec![
+            JsonNode::Integer(1),
+            JsonNode::Integer(1),
+            JsonNode::Float(0.5),
+            JsonNode::Float(1.0),
+            JsonNode::Float(-5.0),
+            JsonNode::RawNumber("1.10".to_owned()),
+        ]));
+
+        let json = json_node.to_json_string();
+        assert_eq!(json, "[1,1,0.5,1.0,-5.0,1.10]");
+        assert_eq!(JsonNode::parse(&json), Ok(JsonNode::Array(vec![
+            JsonNode::Integer(1),
+            JsonNode::Integer(1),
+            JsonNode::Float(0.5),
+            JsonNode::Float(1.0),
+            JsonNode::Float(-5.0),
+            JsonNode::Float(1.1),
+        ])));
+
+        // `f64` parsing also accepts `nan` and `inf`, which become floats that can't be written as JSON instead of text which isn't JSON.
+        let json_node = JsonNode::parse_with_options("[inf]", &options).unwrap();
+        assert_eq!(json_node, JsonNode::Array(vec![JsonNode::Float(f64::INFINITY)]));
+        assert_eq!(json_node.try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("inf".to_owned())));
+        assert_eq!(JsonNode::parse_with_options("nan", &options).unwrap().try_to_json_string(), Err(JsonNodeError::NonFiniteFloat("NaN".to_owned())));
+    }
+
+    #[test]
+    fn parse_unterminated_input() {
+        assert_eq!(JsonNode::parse("[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+        assert_eq!(JsonNode::parse(r#"{"a": [1, {"b": null}]"#), Err(JsonNodeError::UnexpectedEndOfInput { position: 22 }));
+        assert_eq!(JsonNode::parse(r#"{"a": "#), Err(JsonNodeError::UnexpectedEndOfInput { position: 6 }));
+        assert_eq!(JsonNode::parse(r#"["abc"#), Err(JsonNodeError::UnterminatedString { position: 1 }));
+    }
+
+    #[test]
+    fn parse_unterminated_string() {
+        assert_eq!(JsonNode::parse(r#""abc"#), Err(JsonNodeError::UnterminatedString { position: 0 }));
+        assert_eq!(JsonNode::parse(r#""abc\""#), Err(JsonNodeError::UnterminatedString { position: 0 }));
+        assert_eq!(JsonNode::parse(r#"{"a": ["x", "y\"]}"#), Err(JsonNodeError::UnterminatedString { position: 12 }));
+        assert_eq!(JsonNode::parse(r#""abc\\""#), Ok(JsonNode::String("abc\\".to_owned())));
+    }
+
+    #[test]
+    fn parse_unterminated_key() {
+        assert_eq!(JsonNode::parse(r#"{"abc"#), Err(JsonNodeError::UnterminatedString { position: 1 }));
+        assert_eq!(JsonNode::parse(r#"{"abc\": 1}"#), Err(JsonNodeError::UnterminatedString { position: 1 }));
+        assert_eq!(JsonNode::parse(r#"{"a": 1, "b\": 2}"#), Err(JsonNodeError::UnterminatedString { position: 9 }));
+    }
+
+    #[test]
+    fn parse_literals_case_sensitively() {
+        assert_eq!(JsonNode::parse("True"), Err(JsonNodeError::UnexpectedToken { position: 0, token: "True".to_owned() }));
+        assert_eq!(JsonNode::parse("[null, NULL]"), Err(JsonNodeError::UnexpectedToken { position: 7, token: "NULL".to_owned() }));
+
+        let options = ParseOptions {
+            case_insensitive_literals: true,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(JsonNode::parse_with_options("True", &options), Ok(JsonNode::Boolean(true)));
+        assert_eq!(JsonNode::parse_with_options("[FALSE, Null]", &options).unwrap().to_json_string(), "[false,null]");
+        assert!(JsonNode::parse_with_options("Truth", &options).is_err());
+    }
+
+    #[test]
+    fn parse_mixed_integer_ranges() {
+        let node = JsonNode::parse("[1, 18446744073709551615, 2]").unwrap();
+        assert_eq!(node, JsonNode::Array(vec![JsonNode::Integer(1), JsonNode::UInteger(u64::MAX), JsonNode::Integer(2)]));
+
+        assert_eq!(
+            JsonNode::parse("18446744073709551616"),
+            Err(JsonNodeError::InvalidNumber("18446744073709551616 doesn't fit in an i64 or a u64".to_owned()))
+        );
+        assert_eq!(JsonNode::parse("[-9223372036854775809]").unwrap_err().to_string(), "invalid number: -9223372036854775809 doesn't fit in an i64 or a u64");
+        assert_eq!(JsonNode::parse("1.8446744073709552e19"), Ok(JsonNode::Float(1.8446744073709552e19)));
+
+        let options = ParseOptions {
+            oversized_integers_as_floats: true,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(JsonNode::parse_with_options("[1, 18446744073709551615, 2]", &options), Ok(node));
+        assert_eq!(JsonNode::parse_with_options("[1, 18446744073709551616, 2]", &options).unwrap().to_json_string(), "[1,18446744073709552000.0,2]");
+    }
+
     #[test]
     fn parse_true() {
         let json_true = "true";
 
-        let json_node = JsonNode::parse(&json_true).unwrap();
+        let json_node = JsonNode::parse(json_true).unwrap();
         assert_eq!(json_node, JsonNode::Boolean(true));
     }
 
@@ -279,7 +567,7 @@ mod tests {
     fn parse_false() {
         let json_false = "false";
 
-        let json_node = JsonNode::parse(&json_false).unwrap();
+        let json_node = JsonNode::parse(json_false).unwrap();
         assert_eq!(json_node, JsonNode::Boolean(false));
     }
 
@@ -287,18 +575,43 @@ mod tests {
     fn parse_null() {
         let json_null = "null";
 
-        let json_node = JsonNode::parse(&json_null).unwrap();
+        let json_node = JsonNode::parse(json_null).unwrap();
         assert_eq!(json_node, JsonNode::Null);
     }
 
+    #[test]
+    fn parse_with_byte_order_mark() {
+        let json_with_bom = "\u{FEFF}{\"a\":1}";
+
+        let json_node = JsonNode::parse(json_with_bom).unwrap();
+        assert_eq!(json_node, JsonNode::Object(JsonPropertyMap::from([
+            ("a".to_owned(), JsonNode::Integer(1)),
+        ])));
+    }
+
+    #[test]
+    fn parse_keeps_byte_order_mark_inside_string() {
+        let json_with_bom = "\u{FEFF}\"\u{FEFF}text\"";
+
+        let json_node = JsonNode::parse(json_with_bom).unwrap();
+        assert_eq!(json_node, JsonNode::String("\u{FEFF}text".to_owned()));
+    }
+
     #[test]
     fn parse_empty_object() {
         let json_empty_object = "{}";
 
-        let json_node = JsonNode::parse(&json_empty_object).unwrap();
+        let json_node = JsonNode::parse(json_empty_object).unwrap();
         assert_eq!(json_node, JsonNode::Object(JsonPropertyMap::new()));
     }
 
+    #[test]
+    fn parse_empty_containers_with_white_space() {
+        assert_eq!(JsonNode::parse("[\n\t ]").unwrap(), JsonNode::Array(Vec::new()));
+        assert_eq!(JsonNode::parse("{\n }").unwrap(), JsonNode::Object(JsonPropertyMap::new()));
+        assert_eq!(JsonNode::parse("{\"a\": [\r\n], \"b\": {\t}}").unwrap().to_json_string(), r#"{"a":[],"b":{}}"#);
+    }
+
     #[test]
     fn parse_filled_object() {
         let filled_json_object = r#"
@@ -311,7 +624,7 @@ mod tests {
             "null": null
         }"#;
 
-        let json_object_node = JsonNode::parse(&filled_json_object).unwrap();
+        let json_object_node = JsonNode::parse(filled_json_object).unwrap();
         let mut filled_map = HashMap::new();
 
         filled_map.insert("string".to_owned(), JsonNode::String("value".to_owned()));
@@ -321,7 +634,7 @@ mod tests {
         filled_map.insert("false".to_owned(), JsonNode::Boolean(false));
         filled_map.insert("null".to_owned(), JsonNode::Null);
 
-        match json_object_node {
+        match &json_object_node {
             JsonNode::Object(map) => {
                 map.iter().for_each(|(k, v)| {
                     assert_eq!(v, filled_map.get(k).unwrap());
@@ -331,11 +644,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_object_with_white_space_around_colon() {
+        let compact = JsonNode::parse(r#"{"a":1,"b":{"c":[true]}}"#).unwrap();
+
+        for json in [
+            r#"{ "a" : 1, "b" : { "c" : [ true ] } }"#,
+            "{\n\t\"a\"\t:\n1 ,\r\n\"b\":{\"c\" :[true]}}",
+        ] {
+            assert_eq!(JsonNode::parse(json).unwrap(), compact, "{}", json);
+        }
+
+        assert_eq!(compact.as_object().unwrap().get("a"), Some(&JsonNode::Integer(1)));
+    }
+
+    #[test]
+    fn parse_keys_containing_separators() {
+        let json_node = JsonNode::parse(r#"{"a:b": 1, "c,d": 2, "e\"f:": 3, "}": 4}"#).unwrap();
+        let object = json_node.as_object().unwrap();
+
+        assert_eq!(object.len(), 4);
+        assert_eq!(object.get("a:b"), Some(&JsonNode::Integer(1)));
+        assert_eq!(object.get("c,d"), Some(&JsonNode::Integer(2)));
+        assert_eq!(object.get("e\"f:"), Some(&JsonNode::Integer(3)));
+        assert_eq!(object.get("}"), Some(&JsonNode::Integer(4)));
+    }
+
+    #[test]
+    fn parse_values_containing_colons() {
+        let json_node = JsonNode::parse(r#"{"url":"http://example.com/a:b", "list": ["x:y", "{:}"]}"#).unwrap();
+        let object = json_node.as_object().unwrap();
+
+        assert_eq!(object.get("url"), Some(&JsonNode::String("http://example.com/a:b".to_owned())));
+        assert_eq!(object.get("list").unwrap().to_json_string(), r#"["x:y","{:}"]"#);
+    }
+
     #[test]
     fn parse_empty_array() {
         let json_empty_object = "[]";
 
-        let json_node = JsonNode::parse(&json_empty_object).unwrap();
+        let json_node = JsonNode::parse(json_empty_object).unwrap();
         assert_eq!(json_node, JsonNode::Array(Vec::new()));
     }
 
@@ -351,15 +699,15 @@ mod tests {
             null
         ]"#;
         
-        let json_array_node = JsonNode::parse(&filled_json_object).unwrap();
-        let mut filled_array = Vec::new();
-
-        filled_array.push(JsonNode::String("string".to_owned()));
-        filled_array.push(JsonNode::Integer(123));
-        filled_array.push(JsonNode::Float(123.456));
-        filled_array.push(JsonNode::Boolean(true));
-        filled_array.push(JsonNode::Boolean(false));
-        filled_array.push(JsonNode::Null);
+        let json_array_node = JsonNode::parse(filled_json_object).unwrap();
+        let filled_array = vec![
+            JsonNode::String("string".to_owned()),
+            JsonNode::Integer(123),
+            JsonNode::Float(123.456),
+            JsonNode::Boolean(true),
+            JsonNode::Boolean(false),
+            JsonNode::Null,
+        ];
 
         assert_eq!(json_array_node, JsonNode::Array(filled_array));
     }
@@ -389,7 +737,7 @@ mod tests {
             ]
         }"#;
 
-        let parsed_json_tree = JsonNode::parse(&json).unwrap();
+        let parsed_json_tree = JsonNode::parse(json).unwrap();
 
         let constructed_json_tree = JsonNode::Object(JsonPropertyMap::from([
             ("name".to_owned(), JsonNode::String("Jason".to_owned())),
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::parsing::{tokens, JsonNodeParser, RawToken, TokenKind, Tokenizer};
+use crate::Result;
+
+/// A token of JSON text together with the byte range it covers in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    BraceOpen(Range<usize>),
+    BraceClose(Range<usize>),
+    BracketOpen(Range<usize>),
+    BracketClose(Range<usize>),
+    Colon(Range<usize>),
+    Comma(Range<usize>),
+    /// A string with its escapes decoded. The span includes the quotes.
+    String(Cow<'a, str>, Range<usize>),
+    /// A number exactly as it was written.
+    Number(&'a str, Range<usize>),
+    Bool(bool, Range<usize>),
+    Null(Range<usize>),
+}
+
+impl<'a> Token<'a> {
+    /// The byte range of the token in the input.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Token::BraceOpen(span)
+            | Token::BraceClose(span)
+            | Token::BracketOpen(span)
+            | Token::BracketClose(span)
+            | Token::Colon(span)
+            | Token::Comma(span)
+            | Token::String(_, span)
+            | Token::Number(_, span)
+            | Token::Bool(_, span)
+            | Token::Null(span) => span.clone(),
+        }
+    }
+
+    fn from_raw(token: RawToken<'a>) -> Self {
+        let span = token.position..token.position + token.text.len();
+
+        match token.kind {
+            TokenKind::BraceOpen => Token::BraceOpen(span),
+            TokenKind::BraceClose => Token::BraceClose(span),
+            TokenKind::BracketOpen => Token::BracketOpen(span),
+            TokenKind::BracketClose => Token::BracketClose(span),
+            TokenKind::Colon => Token::Colon(span),
+            TokenKind::Comma => Token::Comma(span),
+            TokenKind::String if token.text.contains('\\') => Token::String(Cow::Owned(JsonNodeParser::unescape(token.text)), span),
+            TokenKind::String => Token::String(Cow::Borrowed(&token.text[1..token.text.len() - 1]), span),
+            TokenKind::Number => Token::Number(token.text, span),
+            TokenKind::Boolean => Token::Bool(token.text == tokens::TRUE, span),
+            TokenKind::Null => Token::Null(span),
+        }
+    }
+}
+
+/// Splits JSON text into tokens without checking that they form a valid document.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{Lexer, Token};
+/// 
+/// let tokens = Lexer::new(r#"[1, "a"]"#).collect::<Result<Vec<_>, _>>().unwrap();
+/// 
+/// assert_eq!(tokens[1], Token::Number("1", 1..2));
+/// assert_eq!(tokens[3], Token::String("a".into(), 4..7));
+/// ```
+/// 
+/// # Remarks
+/// 
+/// Only tokens allowed by the JSON grammar are accepted, and the lexer stops after the first error.
+/// A UTF-8 byte order mark at the very start of the input is skipped, but spans are still relative to the whole input.
+/// This is useful for syntax highlighting, linting and other tools which need to know where each token was written.
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    tokenizer: Tokenizer<'a>,
+}
+
+impl<'a> Lexer<'a> {
+    /// Creates a lexer over `input`.
+    pub fn new(input: &'a str) -> Self {
+        let start = input.len() - JsonNodeParser::strip_byte_order_mark(input).len();
+
+        Lexer { tokenizer: Tokenizer::new(input).starting_at(start) }
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokenizer.next().map(|token| token.map(Token::from_raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lexer, Token};
+    use crate::JsonNodeError;
+
+    #[test]
+    fn lex_object_with_spans() {
+        let tokens = Lexer::new(r#"{ "a\nb": [-1.5, true, null], "c": false }"#).collect::<Result<Vec<_>, _>>().unwrap();
+
+        let expected = vec![
+            Token::BraceOpen(0..1),
+            Token::String("a\nb".into(), 2..8),
+            Token::Colon(8..9),
+            Token::BracketOpen(10..11),
+            Token::Number("-1.5", 11..15),
+            Token::Comma(15..16),
+            Token::Bool(true, 17..21),
+            Token::Comma(21..22),
+            Token::Null(23..27),
+            Token::BracketClose(27..28),
+            Token::Comma(28..29),
+            Token::String("c".into(), 30..33),
+            Token::Colon(33..34),
+            Token::Bool(false, 35..40),
+            Token::BraceClose(41..42),
+        ];
+
+        assert_eq!(tokens, expected);
+        assert_eq!(tokens[1].span(), 2..8);
+    }
+
+    #[test]
+    fn lex_stops_at_first_error() {
+        let mut lexer = Lexer::new("\u{FEFF}[1 #]");
+
+        assert_eq!(lexer.next(), Some(Ok(Token::BracketOpen(3..4))));
+        assert_eq!(lexer.next(), Some(Ok(Token::Number("1", 4..5))));
+        assert_eq!(lexer.next(), Some(Err(JsonNodeError::UnexpectedCharacter { position: 6, character: '#' })));
+        assert_eq!(lexer.next(), None);
+    }
+}
@@ -0,0 +1,84 @@
+/// A number whose value changed when it was parsed into an `f64`, as reported by `JsonNode::parse_with_diagnostics`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{JsonNode, LossyNumber};
+/// 
+/// let (_, lossy) = JsonNode::parse_with_diagnostics(r#"{"id": 12345678901234567890123}"#).unwrap();
+/// 
+/// assert_eq!(lossy, vec![LossyNumber { pointer: "/id".to_owned(), text: "12345678901234567890123".to_owned() }]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LossyNumber {
+    /// The JSON Pointer of the number, like `/children/0/height`.
+    pub pointer: String,
+
+    /// The number exactly as it was written in the JSON.
+    pub text: String,
+}
+
+/// Checks if a number token is parsed into an `f64` which doesn't hold the exact value written.
+/// Integers which fit in an `i64` or a `u64` are stored exactly, and `0.1` isn't lossy since it is the shortest text for its `f64`.
+pub(crate) fn is_lossy(text: &str) -> bool {
+    if text.parse::<i64>().is_ok() || text.parse::<u64>().is_ok() {
+        return false;
+    }
+
+    match text.parse::<f64>() {
+        Ok(value) => significant_digits(text) != significant_digits(&format!("{:e}", value)),
+        Err(_) => false,
+    }
+}
+
+/// Splits a number into its sign, its digits without leading or trailing zeros, and the exponent which places the decimal point before the first digit.
+/// This makes `1e2`, `100` and `100.0` compare equal.
+fn significant_digits(text: &str) -> (bool, String, i64) {
+    let (is_negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().unwrap_or(0)),
+        None => (text, 0),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    let leading_zeros = digits.len() - digits.trim_start_matches('0').len();
+    let digits = digits.trim_matches('0');
+
+    match digits.is_empty() {
+        true => (false, String::new(), 0),
+        false => (is_negative, digits.to_owned(), exponent + integer.len() as i64 - leading_zeros as i64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_lossy, LossyNumber};
+    use crate::JsonNode;
+
+    #[test]
+    fn parse_with_diagnostics_flags_lossy_numbers() {
+        let (node, lossy) = JsonNode::parse_with_diagnostics(r#"[0.1, 0.12345678901234567, {"a/b": [-1.0000000000000000001]}]"#).unwrap();
+
+        assert_eq!(node.as_array().unwrap()[0], JsonNode::Float(0.1));
+        assert_eq!(lossy, vec![
+            LossyNumber { pointer: "/1".to_owned(), text: "0.12345678901234567".to_owned() },
+            LossyNumber { pointer: "/2/a~1b/0".to_owned(), text: "-1.0000000000000000001".to_owned() },
+        ]);
+    }
+
+    #[test]
+    fn detect_lossy_numbers() {
+        for text in ["0.1", "1e2", "100.0", "-2.50", "0", "-0.0", "9007199254740993", "18446744073709551615"] {
+            assert!(!is_lossy(text), "{}", text);
+        }
+
+        for text in ["0.12345678901234567", "9007199254740993.0", "1e400", "12345678901234567890123"] {
+            assert!(is_lossy(text), "{}", text);
+        }
+    }
+}
@@ -1,5 +1,18 @@
+pub(crate) mod json_event_reader;
 pub(crate) mod json_node_parser;
+pub(crate) mod lexer;
+pub(crate) mod lossy_number;
+pub(crate) mod parse_options;
+pub(crate) mod scanner;
+pub(crate) mod span_table;
+pub(crate) mod tokenizer;
 pub(crate) mod tokens;
 
+pub use json_event_reader::*;
 pub use json_node_parser::*;
-pub use tokens::*;
\ No newline at end of file
+pub use lexer::*;
+pub use lossy_number::*;
+pub use parse_options::*;
+pub use scanner::*;
+pub use span_table::*;
+pub(crate) use tokenizer::*;
\ No newline at end of file
@@ -0,0 +1,44 @@
+/// Options controlling how JSON is parsed.
+/// 
+/// The default options parse strictly according to the JSON grammar.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{JsonNode, ParseOptions};
+/// 
+/// let options = ParseOptions {
+///     lenient_numbers: true,
+///     ..ParseOptions::default()
+/// };
+/// 
+/// assert_eq!(JsonNode::parse_with_options(".5", &options).unwrap(), JsonNode::Float(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Accept any number Rust's `str::parse` accepts, like `01`, `.5` and `5.`, instead of only numbers allowed by the JSON grammar.
+    /// Defaults to `false`.
+    pub lenient_numbers: bool,
+
+    /// Accept object keys which aren't surrounded by double quotes, like `{name: "Jason"}`.
+    /// Defaults to `false`, which makes unquoted keys fail with `JsonNodeError::InvalidKey`.
+    pub allow_unquoted_keys: bool,
+
+    /// Keep every number exactly as it was written by parsing it into a `JsonNode::RawNumber` instead of an integer or a float.
+    /// This makes serializing the node write the number back byte for byte, so `1.10` doesn't become `1.1`.
+    /// Numbers only accepted because of `lenient_numbers`, like `01`, are still parsed into an integer or a float so they are written back as valid JSON.
+    /// Defaults to `false`.
+    pub preserve_number_text: bool,
+
+    /// Accept `true`, `false` and `null` written in any case, like `True` and `NULL`.
+    /// Defaults to `false`, which only accepts them in lowercase like the JSON grammar does.
+    pub case_insensitive_literals: bool,
+
+    /// Parse integers which fit in neither an `i64` nor a `u64`, like ids beyond `18446744073709551615`, into a `JsonNode::Float` which loses their exact value.
+    /// Defaults to `false`, which makes such integers fail with `JsonNodeError::InvalidNumber`.
+    pub oversized_integers_as_floats: bool,
+
+    /// Make `JsonNode::parse_or_with_options` return its default for invalid JSON too, instead of only for empty JSON.
+    /// Defaults to `false`, which reports invalid JSON as an error. Other ways of parsing ignore this option.
+    pub fallback_on_error: bool,
+}
@@ -0,0 +1,267 @@
+use crate::errors::JsonNodeError;
+use crate::parsing::{JsonNodeParser, ParseOptions, RawToken, TokenKind, Tokenizer};
+use crate::Result;
+
+/// Removes all white space between the tokens of a JSON document without building a `JsonNode` tree.
+/// 
+/// # Arguments
+/// 
+/// * `json` - The JSON to minify.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::minify;
+/// 
+/// let json = r#"
+/// {
+///     "name": "Jason  Jr.",
+///     "numbers": [ 1, 2 ]
+/// }"#;
+/// 
+/// assert_eq!(minify(json).unwrap(), r#"{"name":"Jason  Jr.","numbers":[1,2]}"#);
+/// assert!(minify("[1, 2").is_err());
+/// ```
+/// 
+/// # Remarks
+/// 
+/// Strings and numbers are copied exactly as they were written, including any escapes.
+/// A UTF-8 byte order mark at the very start of `json` is skipped.
+/// 
+/// # Errors
+/// 
+/// Returns an error if `json` isn't a single valid JSON value.
+pub fn minify(json: &str) -> Result<String> {
+    let json = JsonNodeParser::strip_byte_order_mark(json);
+    let mut minified = String::with_capacity(json.len());
+
+    scan(Tokenizer::new(json), |token| minified.push_str(token.text))?;
+
+    Ok(minified)
+}
+
+/// Checks that a JSON document is valid without building a `JsonNode` tree.
+/// 
+/// # Arguments
+/// 
+/// * `json` - The JSON to validate.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{validate, JsonNodeError};
+/// 
+/// assert_eq!(validate(r#"{"numbers": [1, 2]}"#), Ok(()));
+/// assert_eq!(validate("[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+/// ```
+/// 
+/// # Remarks
+/// 
+/// Accepts exactly the documents `JsonNode::parse` accepts and fails with the same errors, so it is a cheaper way to check JSON which is never read.
+/// A UTF-8 byte order mark at the very start of `json` is skipped.
+/// 
+/// # Errors
+/// 
+/// Returns the error `JsonNode::parse` would return for `json`.
+pub fn validate(json: &str) -> Result<()> {
+    let json = JsonNodeParser::strip_byte_order_mark(json);
+    scan(Tokenizer::with_options(json, &ParseOptions::default()), |_| ())
+}
+
+/// The kinds of tokens which may come next while scanning.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Expect {
+    Value,
+    ValueOrBracketClose,
+    Key,
+    KeyOrBraceClose,
+    Colon,
+    CommaOrClose,
+    End,
+}
+
+/// Reads every token from `tokenizer` and checks that they form exactly one JSON value, passing every token to `on_token` in order.
+/// Object keys are read with `Tokenizer::next_key`, so a key token may be an unquoted word.
+pub(crate) fn scan<'a>(mut tokenizer: Tokenizer<'a>, mut on_token: impl FnMut(&RawToken<'a>)) -> Result<()> {
+    scan_value(&mut tokenizer, &mut on_token)?;
+
+    match tokenizer.next() {
+        None => Ok(()),
+        Some(token) => {
+            let token = token?;
+            Err(JsonNodeError::UnexpectedToken { position: token.position, token: token.text.to_owned() })
+        },
+    }
+}
+
+/// Reads the tokens of the next JSON value from `tokenizer`, passing every token to `on_token` in order,
+/// and leaves the tokenizer just after the value.
+pub(crate) fn scan_value<'a>(tokenizer: &mut Tokenizer<'a>, on_token: &mut impl FnMut(&RawToken<'a>)) -> Result<()> {
+    // The opening token of every open object and array.
+    let mut open_containers: Vec<RawToken<'a>> = Vec::new();
+    let mut expect = Expect::Value;
+    let mut is_empty = true;
+
+    while expect != Expect::End {
+        let token = match expect {
+            Expect::Key | Expect::KeyOrBraceClose => tokenizer.next_key(),
+            _ => tokenizer.next(),
+        };
+
+        let Some(token) = token else {
+            break;
+        };
+
+        let token = token?;
+        is_empty = false;
+
+        let is_value = matches!(token.kind, TokenKind::String | TokenKind::Number | TokenKind::Boolean | TokenKind::Null);
+        let is_in_object = |open_containers: &Vec<RawToken>| open_containers.last().map(|open| open.kind) == Some(TokenKind::BraceOpen);
+
+        let closes_value = match (expect, token.kind) {
+            (Expect::Value | Expect::ValueOrBracketClose, _) if is_value => true,
+            (Expect::Value | Expect::ValueOrBracketClose, TokenKind::BraceOpen) => {
+                open_containers.push(token);
+                expect = Expect::KeyOrBraceClose;
+                false
+            },
+            (Expect::Value | Expect::ValueOrBracketClose, TokenKind::BracketOpen) => {
+                open_containers.push(token);
+                expect = Expect::ValueOrBracketClose;
+                false
+            },
+            (Expect::ValueOrBracketClose, TokenKind::BracketClose) | (Expect::KeyOrBraceClose, TokenKind::BraceClose) => {
+                open_containers.pop();
+                true
+            },
+            (Expect::Value | Expect::ValueOrBracketClose, TokenKind::Comma | TokenKind::BracketClose | TokenKind::BraceClose)
+                if !open_containers.is_empty() => {
+                // A value is missing between the separators, like in `[1, ]` or `{"a": }`.
+                let container = open_containers.last().expect("checked that a container is open");
+                return Err(JsonNodeError::EmptyJson(Some(Box::new(container_text(tokenizer, container).to_owned()))));
+            },
+            (Expect::CommaOrClose, TokenKind::BracketClose | TokenKind::BraceClose)
+                if is_in_object(&open_containers) == (token.kind == TokenKind::BraceClose) => {
+                open_containers.pop();
+                true
+            },
+            (Expect::Key | Expect::KeyOrBraceClose, TokenKind::String) => {
+                expect = Expect::Colon;
+                false
+            },
+            (Expect::Colon, TokenKind::Colon) => {
+                expect = Expect::Value;
+                false
+            },
+            (Expect::CommaOrClose, TokenKind::Comma) => {
+                expect = if is_in_object(&open_containers) { Expect::Key } else { Expect::Value };
+                false
+            },
+            _ => return Err(JsonNodeError::UnexpectedToken { position: token.position, token: token.text.to_owned() }),
+        };
+
+        if closes_value {
+            expect = if open_containers.is_empty() { Expect::End } else { Expect::CommaOrClose };
+        }
+
+        on_token(&token);
+    }
+
+    match expect {
+        Expect::End => Ok(()),
+        _ if is_empty => Err(JsonNodeError::EmptyJson(None)),
+        _ => Err(JsonNodeError::UnexpectedEndOfInput { position: Tokenizer::position(tokenizer) }),
+    }
+}
+
+/// Finds the text of the container opened by `open`, up to its matching closing token or the end of the input if it has none.
+fn container_text<'a>(tokenizer: &Tokenizer<'a>, open: &RawToken<'a>) -> &'a str {
+    let input = tokenizer.input();
+    let mut depth = 0;
+
+    for token in tokenizer.starting_at(open.position) {
+        let Ok(token) = token else {
+            break;
+        };
+
+        match token.kind {
+            TokenKind::BraceOpen | TokenKind::BracketOpen => depth += 1,
+            TokenKind::BraceClose | TokenKind::BracketClose => depth -= 1,
+            _ => (),
+        }
+
+        if depth == 0 {
+            return &input[open.position..token.position + token.text.len()];
+        }
+    }
+
+    &input[open.position..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minify, validate};
+    use crate::{JsonNode, JsonNodeError};
+
+    #[test]
+    fn minify_sample() {
+        let json = r#"
+        {
+            "name" :   "Jason",
+            "age": 30,
+            "quote": "a \"quoted\" , text: [with] {brackets}",
+            "numbers": [ 1, 2.50, -3e2 ],
+            "children": [
+                {
+                    "name": "Jasmine",
+                    "tags": [ ],
+                    "parent": { }
+                }
+            ]
+        }
+        "#;
+
+        let expected = r#"{"name":"Jason","age":30,"quote":"a \"quoted\" , text: [with] {brackets}","numbers":[1,2.50,-3e2],"children":[{"name":"Jasmine","tags":[],"parent":{}}]}"#;
+
+        assert_eq!(minify(json).unwrap(), expected);
+        assert_eq!(minify("\u{FEFF} true ").unwrap(), "true");
+        assert_eq!(JsonNode::parse(r#"{"a": [1, {"b": null}]}"#).unwrap().to_json_string(), minify(r#"{ "a" : [ 1 , { "b" : null } ] }"#).unwrap());
+    }
+
+    #[test]
+    fn minify_rejects_invalid_json() {
+        assert_eq!(minify("  "), Err(JsonNodeError::EmptyJson(None)));
+        assert_eq!(minify("[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+        assert_eq!(minify("[1, 2}"), Err(JsonNodeError::UnexpectedToken { position: 5, token: "}".to_owned() }));
+        assert_eq!(minify(r#"{"a" 1}"#), Err(JsonNodeError::UnexpectedToken { position: 5, token: "1".to_owned() }));
+        assert_eq!(minify("[1,]"), Err(JsonNodeError::EmptyJson(Some(Box::new("[1,]".to_owned())))));
+        assert_eq!(minify(r#"{"a": 1,}"#), Err(JsonNodeError::UnexpectedToken { position: 8, token: "}".to_owned() }));
+        assert_eq!(minify("1 2"), Err(JsonNodeError::UnexpectedToken { position: 2, token: "2".to_owned() }));
+        assert_eq!(minify("{1: 2}"), Err(JsonNodeError::InvalidKey("1".to_owned())));
+    }
+
+    #[test]
+    fn validate_matches_parse() {
+        let documents = [
+            r#"{"name": "Jason", "numbers": [1, 2.5, -3e2], "child": {"isMale": true, "spouse": null}}"#,
+            "\u{FEFF}[]",
+            "",
+            "[1, 2",
+            "[1, 2}",
+            "[1, ]",
+            r#"{"a":}"#,
+            "{foo: 1}",
+            "01",
+            "[1] #",
+            r#""abc"#,
+        ];
+
+        for json in documents {
+            assert_eq!(validate(json), JsonNode::parse(json).map(|_| ()), "{}", json);
+        }
+
+        assert_eq!(validate(r#"{"a": [true, false]}"#), Ok(()));
+        assert_eq!(validate("[1, 2"), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+        assert_eq!(validate(r#"{"a": [1 2]}"#), Err(JsonNodeError::UnexpectedToken { position: 9, token: "2".to_owned() }));
+    }
+}
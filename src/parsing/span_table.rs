@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::parsing::{JsonNodeParser, RawToken, TokenKind};
+
+/// The byte range every value of a parsed document was written at, keyed by the JSON Pointer of the value.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::JsonNode;
+/// 
+/// let json = r#"{"children": [{"name": "Jason Jr."}]}"#;
+/// let (node, spans) = JsonNode::parse_with_spans(json).unwrap();
+/// 
+/// assert_eq!(spans.get("/children/0/name"), Some(23..34));
+/// assert_eq!(&json[spans.get("/children/0").unwrap()], r#"{"name": "Jason Jr."}"#);
+/// assert_eq!(spans.get(""), Some(0..json.len()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanTable {
+    spans: HashMap<String, Range<usize>>,
+}
+
+impl SpanTable {
+    /// Gets the byte range of the value at a JSON Pointer as defined by RFC 6901, such as `/children/0/name`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `pointer` - The pointer to the value, written exactly like it is for `JsonNode::pointer`.
+    /// 
+    /// # Remarks
+    /// 
+    /// Strings include their quotes and objects and arrays include their braces or brackets.
+    /// If an object has several properties with the same key, the span of the last one is kept.
+    pub fn get(&self, pointer: &str) -> Option<Range<usize>> {
+        self.spans.get(pointer).cloned()
+    }
+
+    /// Iterates over every pointer and the byte range of its value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Range<usize>)> {
+        self.spans.iter().map(|(pointer, span)| (pointer.as_str(), span.clone()))
+    }
+
+    /// The number of values in the table.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Checks if the table has no values.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+/// An object or array which has been opened but not yet closed.
+struct OpenContainer {
+    pointer: String,
+    start: usize,
+    is_object: bool,
+    next_index: usize,
+}
+
+/// Builds a `SpanTable` from the tokens of a document which the scanner has already checked.
+#[derive(Default)]
+pub(crate) struct SpanRecorder {
+    open_containers: Vec<OpenContainer>,
+    key: Option<String>,
+    pub table: SpanTable,
+}
+
+impl SpanRecorder {
+    pub fn push(&mut self, token: &RawToken) {
+        let is_key = self.open_containers.last().is_some_and(|open| open.is_object)
+            && self.key.is_none()
+            && token.kind == TokenKind::String;
+
+        if is_key {
+            self.key = Some(JsonNodeParser::key_text(token.text));
+            return;
+        }
+
+        let end = token.position + token.text.len();
+
+        match token.kind {
+            TokenKind::BraceOpen | TokenKind::BracketOpen => {
+                let pointer = self.next_pointer();
+
+                self.open_containers.push(OpenContainer {
+                    pointer,
+                    start: token.position,
+                    is_object: token.kind == TokenKind::BraceOpen,
+                    next_index: 0,
+                });
+            },
+            TokenKind::BraceClose | TokenKind::BracketClose => {
+                let open = self.open_containers.pop().expect("the scanner only accepts balanced closing tokens");
+                self.table.spans.insert(open.pointer, open.start..end);
+            },
+            TokenKind::Colon | TokenKind::Comma => (),
+            TokenKind::String | TokenKind::Number | TokenKind::Boolean | TokenKind::Null => {
+                let pointer = self.next_pointer();
+                self.table.spans.insert(pointer, token.position..end);
+            },
+        }
+    }
+
+    /// The pointer of the value which starts next, which is the key or index it has in the innermost open container.
+    fn next_pointer(&mut self) -> String {
+        let Some(parent) = self.open_containers.last_mut() else {
+            return String::new();
+        };
+
+        let segment = match parent.is_object {
+            true => self.key.take().expect("the scanner only accepts values after a key").replace('~', "~0").replace('/', "~1"),
+            false => {
+                parent.next_index += 1;
+                (parent.next_index - 1).to_string()
+            },
+        };
+
+        format!("{}/{}", parent.pointer, segment)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::errors::JsonNodeError;
+use crate::parsing::{tokens, JsonNodeParser, ParseOptions};
+use crate::Result;
+
+/// The kind of a token produced by the `Tokenizer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    BraceOpen,
+    BraceClose,
+    BracketOpen,
+    BracketClose,
+    Colon,
+    Comma,
+    String,
+    Number,
+    Boolean,
+    Null,
+}
+
+/// A token together with its exact text and the byte position where it starts.
+/// The text of a string token includes its quotes and escapes exactly as they were written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RawToken<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub position: usize,
+}
+
+/// Splits JSON text into tokens, skipping the white space between them.
+#[derive(Debug, Clone)]
+pub(crate) struct Tokenizer<'a> {
+    input: &'a str,
+    position: usize,
+    lenient_numbers: bool,
+    allow_unquoted_keys: bool,
+    case_insensitive_literals: bool,
+    reject_oversized_integers: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Creates a tokenizer which only accepts tokens allowed by the JSON grammar.
+    pub fn new(input: &'a str) -> Self {
+        Tokenizer {
+            input,
+            position: 0,
+            lenient_numbers: false,
+            allow_unquoted_keys: false,
+            case_insensitive_literals: false,
+            reject_oversized_integers: false,
+        }
+    }
+
+    /// Creates a tokenizer which accepts the tokens `JsonNode::parse_with_options` accepts with the given options.
+    pub fn with_options(input: &'a str, options: &ParseOptions) -> Self {
+        Tokenizer {
+            lenient_numbers: options.lenient_numbers,
+            allow_unquoted_keys: options.allow_unquoted_keys,
+            case_insensitive_literals: options.case_insensitive_literals,
+            reject_oversized_integers: !options.oversized_integers_as_floats,
+            ..Tokenizer::new(input)
+        }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// A copy of this tokenizer which continues from `position`.
+    pub fn starting_at(&self, position: usize) -> Self {
+        Tokenizer { position, ..self.clone() }
+    }
+
+    /// The byte position just after the last token, or the end of the input once every token has been read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Checks whether only white space is left.
+    pub fn is_at_end(&mut self) -> bool {
+        self.skip_white_space();
+        self.position >= self.input.len()
+    }
+
+    fn skip_white_space(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn read_token(&mut self, start: usize) -> Result<RawToken<'a>> {
+        let bytes = self.input.as_bytes();
+
+        let (kind, end) = match bytes[start] {
+            b'{' => (TokenKind::BraceOpen, start + 1),
+            b'}' => (TokenKind::BraceClose, start + 1),
+            b'[' => (TokenKind::BracketOpen, start + 1),
+            b']' => (TokenKind::BracketClose, start + 1),
+            b':' => (TokenKind::Colon, start + 1),
+            b',' => (TokenKind::Comma, start + 1),
+            b'"' => (TokenKind::String, self.string_end(start)?),
+            b'-' | b'0'..=b'9' | b'+' | b'.' if bytes[start].is_ascii_digit() || bytes[start] == b'-' || self.lenient_numbers => {
+                let end = start + bytes[start..]
+                    .iter()
+                    .take_while(|b| matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+                    .count();
+
+                if !self.is_number(&self.input[start..end]) {
+                    return Err(JsonNodeError::UnexpectedToken { position: start, token: self.input[start..end].to_owned() });
+                }
+
+                if self.reject_oversized_integers && Self::is_oversized_integer(&self.input[start..end]) {
+                    return Err(JsonNodeError::InvalidNumber(format!("{} doesn't fit in an i64 or a u64", &self.input[start..end])));
+                }
+
+                (TokenKind::Number, end)
+            },
+            b'a'..=b'z' | b'A'..=b'Z' => {
+                let end = start + bytes[start..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+                let word = &self.input[start..end];
+
+                let is_literal = |literal: &str| match self.case_insensitive_literals {
+                    true => word.eq_ignore_ascii_case(literal),
+                    false => word == literal,
+                };
+
+                let kind = if is_literal(tokens::TRUE) || is_literal(tokens::FALSE) {
+                    TokenKind::Boolean
+                } else if is_literal(tokens::NULL) {
+                    TokenKind::Null
+                } else if self.is_number(word) {
+                    TokenKind::Number
+                } else {
+                    return Err(JsonNodeError::UnexpectedToken { position: start, token: word.to_owned() });
+                };
+
+                (kind, end)
+            },
+            _ => {
+                let character = self.input[start..].chars().next().unwrap_or_default();
+                return Err(JsonNodeError::UnexpectedCharacter { position: start, character });
+            },
+        };
+
+        self.position = end;
+
+        Ok(RawToken { kind, text: &self.input[start..end], position: start })
+    }
+
+    /// Reads the next token where an object key or the end of an object is expected.
+    /// 
+    /// Keys which aren't a valid quoted string fail with `JsonNodeError::InvalidKey` containing the key up to the colon,
+    /// unless they are bare words and unquoted keys are allowed, in which case they are returned as a string token without quotes.
+    /// Keys whose closing quote is missing fail with `JsonNodeError::UnterminatedString` instead.
+    pub fn next_key(&mut self) -> Option<Result<RawToken<'a>>> {
+        self.skip_white_space();
+
+        let start = self.position;
+        let rest = self.input.get(start..).filter(|rest| !rest.is_empty())?;
+
+        if rest.starts_with(tokens::RIGHT_BRACE) {
+            return self.next();
+        }
+
+        if rest.starts_with(tokens::DOUBLE_QUOTE) {
+            match self.read_token(start) {
+                Ok(token) => return Some(Ok(token)),
+                Err(error @ JsonNodeError::UnterminatedString { .. }) => {
+                    self.position = self.input.len();
+                    return Some(Err(error));
+                },
+                Err(_) => (),
+            }
+        }
+
+        let key = rest[..rest.find([tokens::COLON, tokens::COMMA, tokens::RIGHT_BRACE]).unwrap_or(rest.len())].trim_end();
+        let is_bare = !key.is_empty() && !key.contains(|c: char| c.is_whitespace() || c == tokens::DOUBLE_QUOTE);
+
+        if self.allow_unquoted_keys && is_bare {
+            self.position = start + key.len();
+            return Some(Ok(RawToken { kind: TokenKind::String, text: key, position: start }));
+        }
+
+        self.position = self.input.len();
+
+        Some(Err(JsonNodeError::InvalidKey(key.to_owned())))
+    }
+
+    /// Checks whether a number token is accepted, which depends on whether numbers are parsed leniently.
+    fn is_number(&self, text: &str) -> bool {
+        match self.lenient_numbers {
+            true => text.parse::<f64>().is_ok(),
+            false => JsonNodeParser::is_json_number(text),
+        }
+    }
+
+    /// Checks whether a number token is an integer which fits in neither an `i64` nor a `u64`.
+    fn is_oversized_integer(text: &str) -> bool {
+        !text.contains(['.', 'e', 'E']) && text.parse::<i64>().is_err() && text.parse::<u64>().is_err()
+    }
+
+    /// Finds the end of the string starting at `start`, checking its escapes along the way.
+    /// A backslash escapes the character after it, so `"abc\"` isn't closed by its last quote.
+    fn string_end(&self, start: usize) -> Result<usize> {
+        let mut characters = self.input[start + 1..].char_indices().map(|(index, character)| (start + 1 + index, character));
+
+        while let Some((position, character)) = characters.next() {
+            match character {
+                '"' => return Ok(position + 1),
+                '\\' => match characters.next() {
+                    Some((_, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't')) => (),
+                    Some((_, 'u')) => {
+                        for _ in 0..4 {
+                            match characters.next() {
+                                Some((_, digit)) if digit.is_ascii_hexdigit() => (),
+                                Some((position, character)) => return Err(JsonNodeError::UnexpectedCharacter { position, character }),
+                                None => return Err(JsonNodeError::UnterminatedString { position: start }),
+                            }
+                        }
+                    },
+                    Some((position, character)) => return Err(JsonNodeError::UnexpectedCharacter { position, character }),
+                    None => return Err(JsonNodeError::UnterminatedString { position: start }),
+                },
+                '\u{0}'..='\u{1F}' => return Err(JsonNodeError::UnexpectedCharacter { position, character }),
+                _ => (),
+            }
+        }
+
+        Err(JsonNodeError::UnterminatedString { position: start })
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<RawToken<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_white_space();
+
+        if self.position >= self.input.len() {
+            return None;
+        }
+
+        let token = self.read_token(self.position);
+
+        if token.is_err() {
+            // Stop after the first error rather than trying to recover.
+            self.position = self.input.len();
+        }
+
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawToken, TokenKind, Tokenizer};
+    use crate::JsonNodeError;
+
+    #[test]
+    fn tokenize_object() {
+        let tokens = Tokenizer::new(r#"{ "a\"b": [-1.5e3, true, null] }"#)
+            .map(|token| token.map(|token| (token.kind, token.text, token.position)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let expected = vec![
+            (TokenKind::BraceOpen, "{", 0),
+            (TokenKind::String, r#""a\"b""#, 2),
+            (TokenKind::Colon, ":", 8),
+            (TokenKind::BracketOpen, "[", 10),
+            (TokenKind::Number, "-1.5e3", 11),
+            (TokenKind::Comma, ",", 17),
+            (TokenKind::Boolean, "true", 19),
+            (TokenKind::Comma, ",", 23),
+            (TokenKind::Null, "null", 25),
+            (TokenKind::BracketClose, "]", 29),
+            (TokenKind::BraceClose, "}", 31),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn tokenize_errors() {
+        let first_error = |json: &str| Tokenizer::new(json).find_map(|token| token.err());
+
+        assert_eq!(first_error("[01]"), Some(JsonNodeError::UnexpectedToken { position: 1, token: "01".to_owned() }));
+        assert_eq!(first_error("[True]"), Some(JsonNodeError::UnexpectedToken { position: 1, token: "True".to_owned() }));
+        assert_eq!(first_error("[1] #"), Some(JsonNodeError::UnexpectedCharacter { position: 4, character: '#' }));
+        assert_eq!(first_error(r#""\x""#), Some(JsonNodeError::UnexpectedCharacter { position: 2, character: 'x' }));
+        assert_eq!(first_error(r#""abc"#), Some(JsonNodeError::UnterminatedString { position: 0 }));
+        assert_eq!(first_error(r#"["abc\"]"#), Some(JsonNodeError::UnterminatedString { position: 1 }));
+    }
+
+    #[test]
+    fn tokenize_unterminated_keys() {
+        fn next_key(json: &str) -> Option<Result<RawToken<'_>, JsonNodeError>> {
+            let mut tokenizer = Tokenizer::new(json);
+            tokenizer.next();
+            tokenizer.next_key()
+        }
+
+        assert_eq!(next_key(r#"{"abc"#), Some(Err(JsonNodeError::UnterminatedString { position: 1 })));
+        assert_eq!(next_key(r#"{ "abc\": 1}"#), Some(Err(JsonNodeError::UnterminatedString { position: 2 })));
+        assert_eq!(next_key(r#"{"abc": 1}"#).unwrap().unwrap().text, r#""abc""#);
+    }
+}
@@ -1,14 +1,12 @@
+pub const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
 pub const DOUBLE_QUOTE: char = '"';
 
 pub const COLON: char = ':';
 pub const COMMA: char = ',';
 
-pub const LEFT_BRACE: char = '{';
 pub const RIGHT_BRACE: char = '}';
 
-pub const LEFT_BRACKET: char = '[';
-pub const RIGHT_BRACKET: char = ']';
-
 pub const NULL: &str = "null";
 pub const TRUE: &str = "true";
 pub const FALSE: &str = "false";
\ No newline at end of file
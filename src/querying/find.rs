@@ -0,0 +1,142 @@
+use crate::models::JsonNode;
+
+impl JsonNode {
+    /// Finds the value of every property named `property_name` anywhere in the node tree.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the properties to find.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"id": 1, "items": [{"id": 2}, {"id": 3, "parent": {"id": 1}}]}"#).unwrap();
+    /// 
+    /// let ids = node.find_all("id");
+    /// 
+    /// assert_eq!(ids, vec![&JsonNode::Integer(1), &JsonNode::Integer(2), &JsonNode::Integer(3), &JsonNode::Integer(1)]);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Values are returned in document order, and the search continues inside values which were found.
+    pub fn find_all(&self, property_name: &str) -> Vec<&JsonNode> {
+        let mut found = Vec::new();
+        find_all_into(self, property_name, &mut found);
+        found
+    }
+
+    /// Finds the value of every property named `property_name` anywhere in the node tree as mutable references.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `property_name` - Name of the properties to find.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let mut node = JsonNode::parse(r#"{"items": [{"secret": "a"}, {"secret": "b"}]}"#).unwrap();
+    /// 
+    /// for secret in node.find_all_mut("secret") {
+    ///     *secret = JsonNode::String("***".to_owned());
+    /// }
+    /// 
+    /// assert_eq!(node.to_json_string(), r#"{"items":[{"secret":"***"},{"secret":"***"}]}"#);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Values are returned in document order.
+    /// Unlike `JsonNode::find_all`, the search doesn't continue inside values which were found, because a value and the nodes inside it can't be borrowed mutably at the same time.
+    pub fn find_all_mut(&mut self, property_name: &str) -> Vec<&mut JsonNode> {
+        let mut found = Vec::new();
+        find_all_mut_into(self, property_name, &mut found);
+        found
+    }
+}
+
+fn find_all_into<'a>(node: &'a JsonNode, property_name: &str, found: &mut Vec<&'a JsonNode>) {
+    match node {
+        JsonNode::Object(object) => {
+            for (key, value) in object.iter() {
+                if key == property_name {
+                    found.push(value);
+                }
+
+                find_all_into(value, property_name, found);
+            }
+        },
+        JsonNode::Array(array) => array.iter().for_each(|element| find_all_into(element, property_name, found)),
+        _ => (),
+    }
+}
+
+fn find_all_mut_into<'a>(node: &'a mut JsonNode, property_name: &str, found: &mut Vec<&'a mut JsonNode>) {
+    match node {
+        JsonNode::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if key == property_name {
+                    found.push(value);
+                } else {
+                    find_all_mut_into(value, property_name, found);
+                }
+            }
+        },
+        JsonNode::Array(array) => array.iter_mut().for_each(|element| find_all_mut_into(element, property_name, found)),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::JsonNode;
+
+    const SAMPLE_JSON: &str = r#"
+    {
+        "name": "Jason",
+        "age": 30,
+        "children": [
+            {
+                "name": "Jason Jr.",
+                "age": 5
+            },
+            {
+                "name": "Jasmine",
+                "age": 3
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn find_all_names() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        let names = node.find_all("name")
+            .into_iter()
+            .map(|name| name.as_string().unwrap())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(names, vec!["Jason", "Jason Jr.", "Jasmine"]);
+        assert!(node.find_all("height").is_empty());
+    }
+
+    #[test]
+    fn find_all_mut_ages() {
+        let mut node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        for age in node.find_all_mut("age") {
+            *age.as_integer_mut().unwrap() += 1;
+        }
+
+        let ages = node.find_all("age")
+            .into_iter()
+            .map(|age| *age.as_integer().unwrap())
+            .collect::<Vec<i64>>();
+
+        assert_eq!(ages, vec![31, 6, 4]);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::models::JsonNode;
+
+impl JsonNode {
+    /// Finds every node matching a JSONPath expression.
+    /// 
+    /// Only a subset of JSONPath is supported:
+    /// 
+    /// * `$` - The root node. Every path must start with it.
+    /// * `.key` and `['key']` - The property `key` of an object.
+    /// * `[index]` - The element at `index` of an array.
+    /// * `.*` and `[*]` - Every property of an object or element of an array.
+    /// * `..` - Every node below the current one, followed by any of the selectors above, like `..key` or `..[0]`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - The JSONPath expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use json_node::JsonNode;
+    /// 
+    /// let node = JsonNode::parse(r#"{"children": [{"name": "Jason Jr."}, {"name": "Jasmine"}]}"#).unwrap();
+    /// 
+    /// let names = node.query("$.children[*].name");
+    /// 
+    /// assert_eq!(names, vec![&JsonNode::String("Jason Jr.".to_owned()), &JsonNode::String("Jasmine".to_owned())]);
+    /// ```
+    /// 
+    /// # Remarks
+    /// 
+    /// Nodes are returned in document order.
+    /// Filter expressions, slices and negative indices aren't supported, and a path which uses them or is malformed matches nothing.
+    pub fn query(&self, path: &str) -> Vec<&JsonNode> {
+        let steps = match parse_path(path) {
+            Some(steps) => steps,
+            None => return Vec::new(),
+        };
+
+        steps.iter().fold(vec![self], |nodes, step| {
+            let mut matches = Vec::new();
+
+            for node in nodes {
+                match step {
+                    Step::Child(selector) => select(node, selector, &mut matches),
+                    Step::Descendant(selector) => {
+                        let mut descendants = Vec::new();
+                        collect_descendants(node, &mut descendants);
+                        descendants.into_iter().for_each(|descendant| select(descendant, selector, &mut matches));
+                    },
+                }
+            }
+
+            matches
+        })
+    }
+}
+
+enum Selector {
+    Name(String),
+    Index(usize),
+    Wildcard,
+}
+
+enum Step {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+fn parse_path(path: &str) -> Option<Vec<Step>> {
+    let mut rest = path.trim().strip_prefix('$')?;
+    let mut steps = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("..") {
+            let (selector, after) = match after.strip_prefix('[') {
+                Some(bracketed) => parse_bracketed(bracketed)?,
+                None => parse_dotted(after)?,
+            };
+
+            steps.push(Step::Descendant(selector));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('.') {
+            let (selector, after) = parse_dotted(after)?;
+            steps.push(Step::Child(selector));
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let (selector, after) = parse_bracketed(after)?;
+            steps.push(Step::Child(selector));
+            rest = after;
+        } else {
+            return None;
+        }
+    }
+
+    Some(steps)
+}
+
+/// Parses the selector after a dot, like `key` or `*`.
+fn parse_dotted(path: &str) -> Option<(Selector, &str)> {
+    let end = path.find(['.', '[']).unwrap_or(path.len());
+    let (name, rest) = path.split_at(end);
+
+    match name {
+        "" => None,
+        "*" => Some((Selector::Wildcard, rest)),
+        _ => Some((Selector::Name(name.to_owned()), rest)),
+    }
+}
+
+/// Parses the selector after an opening bracket, like `0]`, `*]` or `'key']`.
+fn parse_bracketed(path: &str) -> Option<(Selector, &str)> {
+    for quote in ['\'', '"'] {
+        if let Some(quoted) = path.strip_prefix(quote) {
+            let end = quoted.find(quote)?;
+            let rest = quoted[end + 1..].strip_prefix(']')?;
+
+            return Some((Selector::Name(quoted[..end].to_owned()), rest));
+        }
+    }
+
+    let end = path.find(']')?;
+    let (inside, rest) = (path[..end].trim(), &path[end + 1..]);
+
+    match inside {
+        "*" => Some((Selector::Wildcard, rest)),
+        _ => Some((Selector::Index(inside.parse().ok()?), rest)),
+    }
+}
+
+fn select<'a>(node: &'a JsonNode, selector: &Selector, matches: &mut Vec<&'a JsonNode>) {
+    match (node, selector) {
+        (JsonNode::Object(object), Selector::Name(name)) => matches.extend(object.get(name)),
+        (JsonNode::Object(object), Selector::Wildcard) => matches.extend(object.nodes()),
+        (JsonNode::Array(array), Selector::Index(index)) => matches.extend(array.get(*index)),
+        (JsonNode::Array(array), Selector::Wildcard) => matches.extend(array.iter()),
+        _ => (),
+    }
+}
+
+/// Collects `node` and every node below it in document order.
+fn collect_descendants<'a>(node: &'a JsonNode, descendants: &mut Vec<&'a JsonNode>) {
+    descendants.push(node);
+
+    match node {
+        JsonNode::Object(object) => object.nodes().into_iter().for_each(|child| collect_descendants(child, descendants)),
+        JsonNode::Array(array) => array.iter().for_each(|child| collect_descendants(child, descendants)),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::JsonNode;
+
+    const SAMPLE_JSON: &str = r#"
+    {
+        "name": "Jason",
+        "age": 30,
+        "numbers": [1, 2, 3, 4, 5],
+        "children": [
+            {
+                "name": "Jason Jr.",
+                "age": 5
+            },
+            {
+                "name": "Jasmine",
+                "age": 3
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn query_children_names() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        let (jason_jr, jasmine) = (JsonNode::String("Jason Jr.".to_owned()), JsonNode::String("Jasmine".to_owned()));
+        let expected = vec![&jason_jr, &jasmine];
+
+        assert_eq!(node.query("$.children[*].name"), expected);
+        assert_eq!(node.query("$['children'].*['name']"), expected);
+        assert_eq!(node.query("$.numbers[1]"), vec![&JsonNode::Integer(2)]);
+        assert_eq!(node.query("$"), vec![&node]);
+    }
+
+    #[test]
+    fn query_recursive_descent() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert_eq!(node.query("$..age"), vec![&JsonNode::Integer(30), &JsonNode::Integer(5), &JsonNode::Integer(3)]);
+        assert_eq!(node.query("$..[0]").len(), 2);
+    }
+
+    #[test]
+    fn query_without_matches() {
+        let node = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        assert!(node.query("$.children[2].name").is_empty());
+        assert!(node.query("$.name.first").is_empty());
+        assert!(node.query("children").is_empty());
+        assert!(node.query("$.children[?(@.age > 4)]").is_empty());
+    }
+}
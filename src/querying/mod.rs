@@ -0,0 +1,2 @@
+mod find;
+mod json_path;
@@ -0,0 +1,287 @@
+use std::io::{self, Write};
+use std::mem;
+
+use crate::models::{JsonNode, JsonPropertyMap};
+use crate::serializing::{FloatFormat, SerializeOptions};
+
+pub struct JsonNodeSerializer;
+
+/// A piece of output which is still to be written, kept on an explicit stack instead of the call stack.
+enum Step<'a> {
+    /// A node nested `depth` levels deep.
+    Value(&'a JsonNode, usize),
+
+    /// The properties of an object nested `depth` levels deep.
+    Object(&'a JsonPropertyMap, usize),
+
+    /// A property name followed by its colon.
+    Key(&'a str),
+
+    /// A line ending followed by the indentation for `depth` levels.
+    NewLine(usize),
+
+    /// Punctuation like a comma or a closing bracket.
+    Raw(&'static [u8]),
+}
+
+/// Like `Step`, but owning the nodes so they are dropped once written.
+enum OwnedStep {
+    Value(JsonNode),
+    Key(String),
+    Raw(&'static [u8]),
+}
+
+impl JsonNodeSerializer {
+    pub fn write_compact<W: Write>(writer: &mut W, node: &JsonNode, options: &SerializeOptions, depth: usize) -> io::Result<()> {
+        Self::write(writer, vec![Step::Value(node, depth)], options, false)
+    }
+
+    pub fn write_compact_object<W: Write>(writer: &mut W, object: &JsonPropertyMap, options: &SerializeOptions, depth: usize) -> io::Result<()> {
+        Self::write(writer, vec![Step::Object(object, depth)], options, false)
+    }
+
+    pub fn write_pretty<W: Write>(writer: &mut W, node: &JsonNode, options: &SerializeOptions, depth: usize) -> io::Result<()> {
+        Self::write(writer, vec![Step::Value(node, depth)], options, true)
+    }
+
+    /// Writes the steps from the end of `steps` until there are none left.
+    /// Objects and arrays push the steps for their children rather than recursing, so deeply nested trees can't overflow the call stack.
+    fn write<W: Write>(writer: &mut W, mut steps: Vec<Step<'_>>, options: &SerializeOptions, is_pretty: bool) -> io::Result<()> {
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Value(node, depth) if Self::is_truncated(node, options, depth) => writer.write_all(options.truncation_placeholder.as_bytes())?,
+                Step::Value(JsonNode::Object(object), depth) => steps.push(Step::Object(object, depth)),
+                Step::Value(JsonNode::Array(array), depth) => {
+                    if array.is_empty() {
+                        writer.write_all(b"[]")?;
+                        continue;
+                    }
+
+                    writer.write_all(b"[")?;
+                    Self::push_closing(&mut steps, b"]", depth, is_pretty);
+
+                    for (index, element) in array.iter().enumerate().rev() {
+                        steps.push(Step::Value(element, depth + 1));
+                        Self::push_separator(&mut steps, index, depth, is_pretty);
+                    }
+                },
+                Step::Value(node, _) => Self::write_scalar(writer, node, options)?,
+                Step::Object(object, depth) => {
+                    let properties = Self::properties(object, options);
+
+                    if properties.is_empty() {
+                        writer.write_all(b"{}")?;
+                        continue;
+                    }
+
+                    writer.write_all(b"{")?;
+                    Self::push_closing(&mut steps, b"}", depth, is_pretty);
+
+                    for (index, (key, value)) in properties.into_iter().enumerate().rev() {
+                        steps.push(Step::Value(value, depth + 1));
+                        steps.push(Step::Key(key));
+                        Self::push_separator(&mut steps, index, depth, is_pretty);
+                    }
+                },
+                Step::Key(key) => {
+                    Self::write_string(writer, key, options)?;
+                    writer.write_all(if is_pretty { b": " } else { b":" })?;
+                },
+                Step::NewLine(depth) => Self::write_new_line(writer, options, depth)?,
+                Step::Raw(bytes) => writer.write_all(bytes)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pushes the closing bracket or brace of a container, on its own line when pretty printing.
+    fn push_closing(steps: &mut Vec<Step<'_>>, closing: &'static [u8], depth: usize, is_pretty: bool) {
+        steps.push(Step::Raw(closing));
+
+        if is_pretty {
+            steps.push(Step::NewLine(depth));
+        }
+    }
+
+    /// Pushes what comes before the child at `index` of a container, which is a comma unless it is the first child and a new line when pretty printing.
+    /// Since steps are written from the end, this is pushed after the child itself.
+    fn push_separator(steps: &mut Vec<Step<'_>>, index: usize, depth: usize, is_pretty: bool) {
+        if is_pretty {
+            steps.push(Step::NewLine(depth + 1));
+        }
+
+        if index > 0 {
+            steps.push(Step::Raw(b","));
+        }
+    }
+
+    /// Writes a node like `write_compact`, dropping every child as soon as it has been written.
+    /// Omitted properties are skipped just like they are when borrowing, but `max_depth` and the key order options aren't used.
+    pub fn write_compact_owned<W: Write>(writer: &mut W, node: JsonNode, options: &SerializeOptions) -> io::Result<()> {
+        let mut steps = vec![OwnedStep::Value(node)];
+
+        while let Some(mut step) = steps.pop() {
+            match step {
+                OwnedStep::Value(JsonNode::Object(ref mut object)) => {
+                    let properties = object
+                        .drain()
+                        .filter(|(_, value)| !value.is_omitted_from_objects())
+                        .collect::<Vec<_>>();
+
+                    writer.write_all(b"{")?;
+                    steps.push(OwnedStep::Raw(b"}"));
+
+                    for (index, (key, value)) in properties.into_iter().enumerate().rev() {
+                        steps.push(OwnedStep::Value(value));
+                        steps.push(OwnedStep::Key(key));
+
+                        if index > 0 {
+                            steps.push(OwnedStep::Raw(b","));
+                        }
+                    }
+                },
+                OwnedStep::Value(JsonNode::Array(ref mut array)) => {
+                    writer.write_all(b"[")?;
+                    steps.push(OwnedStep::Raw(b"]"));
+
+                    for (index, element) in mem::take(array).into_iter().enumerate().rev() {
+                        steps.push(OwnedStep::Value(element));
+
+                        if index > 0 {
+                            steps.push(OwnedStep::Raw(b","));
+                        }
+                    }
+                },
+                OwnedStep::Value(node) => Self::write_scalar(writer, &node, options)?,
+                OwnedStep::Key(key) => {
+                    Self::write_string(writer, &key, options)?;
+                    writer.write_all(b":")?;
+                },
+                OwnedStep::Raw(bytes) => writer.write_all(bytes)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the node is an object or array nested at least `max_depth` levels deep, so the placeholder is written instead of it.
+    fn is_truncated(node: &JsonNode, options: &SerializeOptions, depth: usize) -> bool {
+        (node.is_object() || node.is_array()) && options.max_depth.is_some_and(|max_depth| depth >= max_depth)
+    }
+
+    /// The properties of an object which are written, in the order the options ask for.
+    fn properties<'a>(object: &'a JsonPropertyMap, options: &SerializeOptions) -> Vec<&'a (String, JsonNode)> {
+        let mut properties = object
+            .iter()
+            .filter(|(_, value)| !value.is_omitted_from_objects())
+            .collect::<Vec<_>>();
+
+        if options.sort_keys {
+            properties.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+
+        if !options.key_order.is_empty() {
+            // Listed keys get their position in the order and every other key comes after them, keeping its place since the sort is stable.
+            let rank = |key: &str| options.key_order.iter().position(|listed| listed == key).unwrap_or(options.key_order.len());
+            properties.sort_by_key(|(key, _)| rank(key));
+        }
+
+        properties
+    }
+
+    fn write_scalar<W: Write>(writer: &mut W, node: &JsonNode, options: &SerializeOptions) -> io::Result<()> {
+        match node {
+            JsonNode::String(value) => Self::write_string(writer, value, options),
+            JsonNode::Integer(value) => write!(writer, "{}", value),
+            JsonNode::UInteger(value) => write!(writer, "{}", value),
+            JsonNode::Float(value) => writer.write_all(Self::format_float_with(*value, options).as_bytes()),
+            JsonNode::Boolean(value) => write!(writer, "{}", value),
+            JsonNode::Null => writer.write_all(b"null"),
+            #[cfg(feature = "undefined")]
+            JsonNode::Undefined => writer.write_all(b"null"),
+            JsonNode::RawNumber(text) => writer.write_all(text.as_bytes()),
+            JsonNode::Object(_) | JsonNode::Array(_) => Self::write_compact(writer, node, options, 0),
+        }
+    }
+
+    /// Writes a string surrounded by double quotes, escaping the characters JSON requires and those the options ask for.
+    pub fn write_string<W: Write>(writer: &mut W, value: &str, options: &SerializeOptions) -> io::Result<()> {
+        Self::write_escaped(writer, value, options.escape_forward_slashes, options.ascii_only)
+    }
+
+    /// Writes a string surrounded by double quotes, writing the runs of characters which need no escaping as they are.
+    /// 
+    /// `"`, `\` and control characters are always escaped, `/` is escaped as `\/` if `escape_forward_slashes` is set,
+    /// and every non-ASCII character is escaped as `\uXXXX` if `ascii_only` is set.
+    pub(crate) fn write_escaped<W: Write + ?Sized>(writer: &mut W, value: &str, escape_forward_slashes: bool, ascii_only: bool) -> io::Result<()> {
+        writer.write_all(b"\"")?;
+
+        let mut unescaped_start = 0;
+
+        for (index, character) in value.char_indices() {
+            let escaped = match character {
+                '"' => Some("\\\""),
+                '\\' => Some("\\\\"),
+                '/' if escape_forward_slashes => Some("\\/"),
+                '\u{8}' => Some("\\b"),
+                '\u{C}' => Some("\\f"),
+                '\n' => Some("\\n"),
+                '\r' => Some("\\r"),
+                '\t' => Some("\\t"),
+                '\u{0}'..='\u{1F}' => None,
+                _ if ascii_only && !character.is_ascii() => None,
+                _ => continue,
+            };
+
+            writer.write_all(&value.as_bytes()[unescaped_start..index])?;
+            unescaped_start = index + character.len_utf8();
+
+            match escaped {
+                Some(escaped) => writer.write_all(escaped.as_bytes())?,
+                // Characters outside the Basic Multilingual Plane are written as a surrogate pair.
+                None => {
+                    for unit in character.encode_utf16(&mut [0; 2]) {
+                        write!(writer, "\\u{:04x}", unit)?;
+                    }
+                },
+            }
+        }
+
+        writer.write_all(&value.as_bytes()[unescaped_start..])?;
+        writer.write_all(b"\"")
+    }
+
+    /// Formats a float as the `float_format` and `integral_floats_keep_decimal` options describe.
+    /// By default that is the fewest digits which still parse back to the exact same `f64`, keeping a `.0` on integral floats.
+    pub fn format_float_with(value: f64, options: &SerializeOptions) -> String {
+        let formatted = match options.float_format {
+            // `Display` for `f64` writes the shortest representation which round-trips, so `0.1 + 0.2` becomes `0.30000000000000004`.
+            FloatFormat::Shortest => value.to_string(),
+            FloatFormat::Decimals(decimals) => format!("{:.*}", decimals, value),
+            FloatFormat::SignificantDigits(digits) => {
+                // Rounding through scientific notation keeps the requested number of digits wherever the decimal point is.
+                let rounded = format!("{:.*e}", digits.saturating_sub(1), value);
+                rounded.parse().unwrap_or(value).to_string()
+            },
+        };
+
+        let looks_like_integer = value.is_finite() && !formatted.contains(['.', 'e', 'E']);
+
+        if options.integral_floats_keep_decimal && looks_like_integer {
+            formatted + ".0"
+        } else {
+            formatted
+        }
+    }
+
+    fn write_new_line<W: Write>(writer: &mut W, options: &SerializeOptions, depth: usize) -> io::Result<()> {
+        writer.write_all(options.line_ending.as_str().as_bytes())?;
+
+        for _ in 0..depth {
+            writer.write_all(options.indent.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
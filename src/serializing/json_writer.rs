@@ -0,0 +1,332 @@
+use std::io::{self, Write};
+
+use crate::models::JsonNode;
+use crate::serializing::{JsonNodeSerializer, SerializeOptions};
+use crate::ToJsonNode;
+
+/// Writes JSON piece by piece without building a `JsonNode` tree, keeping track of the commas and indentation.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::JsonWriter;
+/// 
+/// let mut writer = JsonWriter::compact(Vec::new());
+/// 
+/// writer.begin_object().unwrap();
+/// writer.key("name").unwrap();
+/// writer.value("Jason").unwrap();
+/// writer.key("numbers").unwrap();
+/// writer.begin_array().unwrap();
+/// 
+/// for number in 1..=3 {
+///     writer.value(number).unwrap();
+/// }
+/// 
+/// writer.end().unwrap();
+/// writer.end().unwrap();
+/// 
+/// assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), r#"{"name":"Jason","numbers":[1,2,3]}"#);
+/// ```
+#[derive(Debug)]
+pub struct JsonWriter<W: Write> {
+    writer: W,
+    options: SerializeOptions,
+    is_pretty: bool,
+    open_containers: Vec<OpenContainer>,
+    has_key: bool,
+    has_root: bool,
+}
+
+/// An object or array which has been begun but not yet ended.
+#[derive(Debug)]
+struct OpenContainer {
+    is_object: bool,
+    is_empty: bool,
+}
+
+impl<W: Write> JsonWriter<W> {
+    /// Creates a writer which writes compact JSON, like `JsonNode::to_json_string`.
+    pub fn compact(writer: W) -> Self {
+        JsonWriter { writer, options: SerializeOptions::default(), is_pretty: false, open_containers: Vec::new(), has_key: false, has_root: false }
+    }
+
+    /// Creates a writer which writes indented JSON, like `JsonNode::write_json_pretty_with`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `writer` - The writer the JSON is written to.
+    /// * `options` - Controls the indentation, line endings and how floats are written.
+    pub fn pretty(writer: W, options: SerializeOptions) -> Self {
+        JsonWriter { writer, options, is_pretty: true, open_containers: Vec::new(), has_key: false, has_root: false }
+    }
+
+    /// Begins an object. Properties are written with `JsonWriter::key` followed by a value until `JsonWriter::end` is called.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if a value isn't allowed here.
+    pub fn begin_object(&mut self) -> io::Result<()> {
+        self.before_value()?;
+        self.open_containers.push(OpenContainer { is_object: true, is_empty: true });
+        self.writer.write_all(b"{")
+    }
+
+    /// Begins an array. Elements are written as values until `JsonWriter::end` is called.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if a value isn't allowed here.
+    pub fn begin_array(&mut self) -> io::Result<()> {
+        self.before_value()?;
+        self.open_containers.push(OpenContainer { is_object: false, is_empty: true });
+        self.writer.write_all(b"[")
+    }
+
+    /// Writes the key of the next property in the current object.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The name of the property, written inside double quotes.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if the writer isn't inside an object or the previous key has no value yet.
+    pub fn key(&mut self, key: &str) -> io::Result<()> {
+        let is_in_object = matches!(self.open_containers.last(), Some(container) if container.is_object);
+
+        if !is_in_object || self.has_key {
+            return Err(invalid_input("a key can only be written inside an object, before the value of the property"));
+        }
+
+        self.before_element()?;
+        self.has_key = true;
+
+        JsonNodeSerializer::write_string(&mut self.writer, key, &self.options)?;
+        self.writer.write_all(if self.is_pretty { b": " } else { b":" })
+    }
+
+    /// Writes a value, which may itself be a whole tree.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `value` - Anything implementing `ToJsonNode`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if a value isn't allowed here.
+    pub fn value<T: ToJsonNode>(&mut self, value: T) -> io::Result<()> {
+        self.node(&value.to_json_node())
+    }
+
+    /// Writes a `JsonNode` tree as a value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `node` - The tree to write.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if a value isn't allowed here.
+    pub fn node(&mut self, node: &JsonNode) -> io::Result<()> {
+        self.before_value()?;
+
+        match self.is_pretty {
+            true => JsonNodeSerializer::write_pretty(&mut self.writer, node, &self.options, self.open_containers.len()),
+            false => JsonNodeSerializer::write_compact(&mut self.writer, node, &self.options, self.open_containers.len()),
+        }
+    }
+
+    /// Writes `null` as a value.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if a value isn't allowed here.
+    pub fn null(&mut self) -> io::Result<()> {
+        self.node(&JsonNode::Null)
+    }
+
+    /// Ends the innermost object or array.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error produced by the writer, or an `io::ErrorKind::InvalidInput` error if nothing is open or a key has no value yet.
+    pub fn end(&mut self) -> io::Result<()> {
+        if self.has_key {
+            return Err(invalid_input("the last key has no value"));
+        }
+
+        let container = self.open_containers.pop().ok_or_else(|| invalid_input("there is no object or array to end"))?;
+
+        if !container.is_empty {
+            self.write_new_line()?;
+        }
+
+        self.writer.write_all(if container.is_object { b"}" } else { b"]" })
+    }
+
+    /// Unwraps the writer the JSON was written to.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Prepares for a value, writing the separator before it if it is an element of an array.
+    fn before_value(&mut self) -> io::Result<()> {
+        match self.open_containers.last() {
+            Some(container) if container.is_object => {
+                if !self.has_key {
+                    return Err(invalid_input("a value inside an object must come after a key"));
+                }
+
+                self.has_key = false;
+                Ok(())
+            },
+            Some(_) => self.before_element(),
+            None if self.has_root => Err(invalid_input("the root value has already been written")),
+            None => {
+                self.has_root = true;
+                Ok(())
+            },
+        }
+    }
+
+    /// Writes the comma and new-line which come before every element of an array and every property of an object.
+    fn before_element(&mut self) -> io::Result<()> {
+        let container = self.open_containers.last_mut().expect("elements are only written inside a container");
+
+        if !container.is_empty {
+            self.writer.write_all(b",")?;
+        }
+
+        container.is_empty = false;
+        self.write_new_line()
+    }
+
+    fn write_new_line(&mut self) -> io::Result<()> {
+        if !self.is_pretty {
+            return Ok(());
+        }
+
+        self.writer.write_all(self.options.line_ending.as_str().as_bytes())?;
+
+        for _ in 0..self.open_containers.len() {
+            self.writer.write_all(self.options.indent.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonWriter;
+    use crate::{JsonNode, SerializeOptions};
+
+    const SAMPLE_JSON: &str = r#"
+    {
+        "name": "Jason",
+        "age": 30,
+        "isMale": true,
+        "height": 1.8,
+        "numbers": [1, 2, 3, 4, 5],
+        "children": [
+            {
+                "name": "Jasmine",
+                "age": 3,
+                "spouse": null,
+                "tags": [],
+                "pets": {}
+            }
+        ]
+    }"#;
+
+    fn write_sample(writer: &mut JsonWriter<Vec<u8>>) -> std::io::Result<()> {
+        writer.begin_object()?;
+        writer.key("name")?;
+        writer.value("Jason")?;
+        writer.key("age")?;
+        writer.value(30)?;
+        writer.key("isMale")?;
+        writer.value(true)?;
+        writer.key("height")?;
+        writer.value(1.8)?;
+        writer.key("numbers")?;
+        writer.value(vec![1, 2, 3, 4, 5])?;
+        writer.key("children")?;
+        writer.begin_array()?;
+        writer.begin_object()?;
+        writer.key("name")?;
+        writer.value("Jasmine")?;
+        writer.key("age")?;
+        writer.value(3)?;
+        writer.key("spouse")?;
+        writer.null()?;
+        writer.key("tags")?;
+        writer.begin_array()?;
+        writer.end()?;
+        writer.key("pets")?;
+        writer.begin_object()?;
+        writer.end()?;
+        writer.end()?;
+        writer.end()?;
+        writer.end()
+    }
+
+    #[test]
+    fn write_sample_pretty_and_compact() {
+        let sample = JsonNode::parse(SAMPLE_JSON).unwrap();
+
+        let mut pretty = JsonWriter::pretty(Vec::new(), SerializeOptions::default());
+        write_sample(&mut pretty).unwrap();
+        assert_eq!(String::from_utf8(pretty.into_inner()).unwrap(), sample.to_json_string_pretty());
+
+        let mut compact = JsonWriter::compact(Vec::new());
+        write_sample(&mut compact).unwrap();
+        assert_eq!(String::from_utf8(compact.into_inner()).unwrap(), sample.to_json_string());
+    }
+
+    #[test]
+    fn write_rejects_misplaced_tokens() {
+        let mut writer = JsonWriter::compact(Vec::new());
+
+        assert!(writer.key("a").is_err());
+        assert!(writer.end().is_err());
+
+        writer.begin_object().unwrap();
+        assert!(writer.value(1).is_err());
+
+        writer.key("a").unwrap();
+        assert!(writer.key("b").is_err());
+        assert!(writer.end().is_err());
+
+        writer.node(&JsonNode::parse("[1, {}]").unwrap()).unwrap();
+        writer.end().unwrap();
+
+        assert_eq!(writer.into_inner(), br#"{"a":[1,{}]}"#);
+    }
+
+    #[test]
+    fn write_rejects_a_second_root_value() {
+        let mut writer = JsonWriter::compact(Vec::new());
+
+        writer.value(1).unwrap();
+        assert!(writer.value(2).is_err());
+        assert!(writer.begin_array().is_err());
+        assert!(writer.node(&JsonNode::Null).is_err());
+
+        assert_eq!(writer.into_inner(), b"1");
+
+        let mut writer = JsonWriter::compact(Vec::new());
+
+        writer.begin_array().unwrap();
+        writer.end().unwrap();
+
+        let error = writer.null().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(writer.into_inner(), b"[]");
+    }
+}
@@ -0,0 +1,9 @@
+pub(crate) mod json_node_serializer;
+pub(crate) mod json_writer;
+pub(crate) mod reformat;
+pub(crate) mod serialize_options;
+
+pub use json_node_serializer::*;
+pub use json_writer::*;
+pub use reformat::*;
+pub use serialize_options::*;
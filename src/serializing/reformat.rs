@@ -0,0 +1,74 @@
+use crate::models::JsonNode;
+use crate::serializing::SerializeOptions;
+use crate::Result;
+
+/// Parses a JSON document and writes it back as indented JSON formatted with the given options, like `jq .` does.
+/// 
+/// # Arguments
+/// 
+/// * `json` - The JSON to reformat.
+/// * `options` - Controls the indentation, line endings, key order and how floats and strings are written.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{reformat, SerializeOptions};
+/// 
+/// let options = SerializeOptions {
+///     indent: "  ".to_owned(),
+///     sort_keys: true,
+///     ..SerializeOptions::default()
+/// };
+/// 
+/// assert_eq!(reformat(r#"{"b":[1,2],"a":null}"#, &options).unwrap(), "{\n  \"a\": null,\n  \"b\": [\n    1,\n    2\n  ]\n}");
+/// ```
+/// 
+/// # Remarks
+/// 
+/// Use `minify` instead to remove the white space from a document.
+/// A UTF-8 byte order mark at the very start of `json` is skipped.
+/// 
+/// # Errors
+/// 
+/// Returns the error `JsonNode::parse` would return for `json`.
+pub fn reformat(json: &str, options: &SerializeOptions) -> Result<String> {
+    let node = JsonNode::parse(json)?;
+    let mut buffer = Vec::new();
+
+    node.write_json_pretty_with(&mut buffer, options)?;
+
+    Ok(String::from_utf8(buffer).expect("serialized JSON is always valid UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reformat;
+    use crate::{JsonNodeError, SerializeOptions};
+
+    #[test]
+    fn reformat_sorts_and_indents() {
+        let json = r#"{"name":"Jason","age":30,"children":[{"name":"Jasmine","isMale":false,"height":1.1}],"empty":{}}"#;
+
+        let options = SerializeOptions {
+            indent: "  ".to_owned(),
+            sort_keys: true,
+            ..SerializeOptions::default()
+        };
+
+        let expected = r#"{
+  "age": 30,
+  "children": [
+    {
+      "height": 1.1,
+      "isMale": false,
+      "name": "Jasmine"
+    }
+  ],
+  "empty": {},
+  "name": "Jason"
+}"#;
+
+        assert_eq!(reformat(json, &options).unwrap(), expected);
+        assert_eq!(reformat("[1, 2", &options), Err(JsonNodeError::UnexpectedEndOfInput { position: 5 }));
+    }
+}
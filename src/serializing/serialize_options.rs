@@ -0,0 +1,105 @@
+/// Options controlling how a `JsonNode` is serialized.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use json_node::{LineEnding, SerializeOptions};
+/// 
+/// let options = SerializeOptions {
+///     indent: "\t".to_owned(),
+///     line_ending: LineEnding::CrLf,
+///     ..SerializeOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerializeOptions {
+    /// The string written once per level of nesting when pretty printing. Defaults to four spaces.
+    pub indent: String,
+
+    /// The line ending written between lines when pretty printing. Defaults to `LineEnding::Lf`.
+    pub line_ending: LineEnding,
+
+    /// How floats are rounded when they are written. Defaults to `FloatFormat::Shortest`.
+    pub float_format: FloatFormat,
+
+    /// Write `.0` after floats which would otherwise look like integers, so `2.0` is written as `2.0` rather than `2` and parses back as a float.
+    /// Defaults to `true`. Turning it off writes such floats exactly like integers.
+    pub integral_floats_keep_decimal: bool,
+
+    /// Write every `/` in strings as `\/`, so a string containing `</script>` can't end a script element when the JSON is embedded in HTML.
+    /// Defaults to `false`.
+    pub escape_forward_slashes: bool,
+
+    /// Write every non-ASCII character in strings as a `\uXXXX` escape, using a surrogate pair for characters like emoji, so the output is pure ASCII.
+    /// Defaults to `false`, which writes such characters as UTF-8.
+    pub ascii_only: bool,
+
+    /// Write the properties of every object sorted by key rather than in the order they were inserted.
+    /// Defaults to `false`.
+    pub sort_keys: bool,
+
+    /// Property names written first in every object, in this order. The remaining properties follow in insertion order, or sorted if `sort_keys` is set.
+    /// Names which an object doesn't have are skipped. Defaults to empty.
+    pub key_order: Vec<String>,
+
+    /// How many levels of objects and arrays are written before deeper ones are replaced by `truncation_placeholder`, counting the root as level one.
+    /// Defaults to `None`, which writes the whole tree.
+    pub max_depth: Option<usize>,
+
+    /// The text written in place of objects and arrays nested deeper than `max_depth`. It is written as is, so it must be valid JSON for the output to be.
+    /// Defaults to `"..."`, a JSON string.
+    pub truncation_placeholder: String,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            indent: "    ".to_owned(),
+            line_ending: LineEnding::Lf,
+            float_format: FloatFormat::Shortest,
+            integral_floats_keep_decimal: true,
+            escape_forward_slashes: false,
+            ascii_only: false,
+            sort_keys: false,
+            key_order: Vec::new(),
+            max_depth: None,
+            truncation_placeholder: "\"...\"".to_owned(),
+        }
+    }
+}
+
+/// The style of new-lines written when pretty printing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// Unix style `\n`.
+    #[default]
+    Lf,
+
+    /// Windows style `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the characters making up the line ending.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// How floats are rounded when they are serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatFormat {
+    /// The fewest digits which still parse back to the exact same `f64`.
+    #[default]
+    Shortest,
+
+    /// A fixed number of digits after the decimal point, so `FloatFormat::Decimals(2)` writes `0.1 + 0.2` as `0.30`.
+    Decimals(usize),
+
+    /// Rounded to a number of significant digits, then written with the fewest digits, so `FloatFormat::SignificantDigits(3)` writes `1234.5` as `1230.0`.
+    /// Zero is treated as one.
+    SignificantDigits(usize),
+}
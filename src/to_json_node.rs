@@ -1,5 +1,8 @@
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
 use std::error::Error;
+use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{JsonNode, JsonPropertyMap};
 
@@ -58,6 +61,37 @@ impl ToJsonNode for &str {
     }
 }
 
+impl ToJsonNode for str {
+    fn to_json_node(&self) -> JsonNode {
+        JsonNode::String(self.to_string())
+    }
+}
+
+impl<T: ToJsonNode + ?Sized> ToJsonNode for Box<T> {
+    fn to_json_node(&self) -> JsonNode {
+        T::to_json_node(self)
+    }
+}
+
+impl<T: ToJsonNode + ?Sized> ToJsonNode for Rc<T> {
+    fn to_json_node(&self) -> JsonNode {
+        T::to_json_node(self)
+    }
+}
+
+impl<T: ToJsonNode + ?Sized> ToJsonNode for Arc<T> {
+    fn to_json_node(&self) -> JsonNode {
+        T::to_json_node(self)
+    }
+}
+
+/// Converts the borrowed or owned value alike, so `Cow<str>` becomes a `JsonNode::String`.
+impl<T: ToJsonNode + ToOwned + ?Sized> ToJsonNode for Cow<'_, T> {
+    fn to_json_node(&self) -> JsonNode {
+        T::to_json_node(self)
+    }
+}
+
 impl ToJsonNode for i32 {
     fn to_json_node(&self) -> JsonNode {
         JsonNode::Integer(i64::from(*self))
@@ -88,6 +122,15 @@ impl ToJsonNode for u32 {
     }
 }
 
+impl ToJsonNode for u64 {
+    fn to_json_node(&self) -> JsonNode {
+        match i64::try_from(*self) {
+            Ok(value) => JsonNode::Integer(value),
+            Err(_) => JsonNode::UInteger(*self),
+        }
+    }
+}
+
 impl ToJsonNode for bool {
     fn to_json_node(&self) -> JsonNode {
         JsonNode::Boolean(*self)
@@ -451,4 +494,23 @@ mod tests {
             r#"{"name":"John Doe","age":42}"#
         );
     }
+
+    #[test]
+    fn smart_pointers_delegate_to_their_value() {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        use crate::{JsonNode, ToJsonNode};
+
+        assert_eq!(Box::new(42i64).to_json_node(), JsonNode::Integer(42));
+        assert_eq!(Arc::new("Jason".to_owned()).to_json_node(), JsonNode::String("Jason".to_owned()));
+        assert_eq!(Rc::new(vec![true]).to_json_node().to_json_string(), "[true]");
+        assert_eq!(Cow::Borrowed("borrowed").to_json_node(), JsonNode::String("borrowed".to_owned()));
+        assert_eq!(Cow::<str>::Owned("owned".to_owned()).to_json_node(), JsonNode::String("owned".to_owned()));
+        assert_eq!(Arc::<str>::from("shared").to_json_node(), JsonNode::String("shared".to_owned()));
+
+        let boxed: Box<[i32]> = Box::new([1, 2]);
+        assert_eq!(boxed.to_json_node().to_json_string(), "[1,2]");
+    }
 }
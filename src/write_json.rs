@@ -0,0 +1,233 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::serializing::{JsonNodeSerializer, SerializeOptions};
+use crate::JsonNode;
+
+/// A trait for writing a type as compact JSON straight into a writer, without converting it into a `JsonNode` first.
+///
+/// This is what `json_write!` uses for the values it interpolates.
+pub trait WriteJson {
+    /// Writes the type as compact JSON, the same way `JsonNode::to_json_string` would write its `JsonNode`.
+    ///
+    /// # Implementing the Trait
+    ///
+    /// ```
+    /// use std::io::{self, Write};
+    ///
+    /// use json_node::WriteJson;
+    ///
+    /// // Define some type you want to write as JSON.
+    /// struct Celsius(f64);
+    ///
+    /// // Implement the trait by writing the parts of the type with their own implementations.
+    /// impl WriteJson for Celsius {
+    ///     fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+    ///         writer.write_all(b"{\"celsius\":")?;
+    ///         self.0.write_json(writer)?;
+    ///         writer.write_all(b"}")
+    ///     }
+    /// }
+    ///
+    /// let mut buffer = Vec::new();
+    /// Celsius(21.5).write_json(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(buffer, br#"{"celsius":21.5}"#);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by the writer.
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+impl WriteJson for JsonNode {
+    fn write_json(&self, mut writer: &mut dyn Write) -> io::Result<()> {
+        JsonNodeSerializer::write_compact(&mut writer, self, &SerializeOptions::default(), 0)
+    }
+}
+
+impl WriteJson for str {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        JsonNodeSerializer::write_escaped(writer, self, false, false)
+    }
+}
+
+impl WriteJson for String {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        self.as_str().write_json(writer)
+    }
+}
+
+impl WriteJson for bool {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+}
+
+macro_rules! impl_write_json_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl WriteJson for $integer {
+                fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+                    write!(writer, "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_json_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Floats are written like `JsonNode::Float` with the default `SerializeOptions`, so integral floats keep a `.0`.
+macro_rules! impl_write_json_for_float {
+    ($($float:ty),*) => {
+        $(
+            impl WriteJson for $float {
+                fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+                    let value = f64::from(*self);
+                    write!(writer, "{}", value)?;
+
+                    // `Display` for `f64` never uses an exponent, so an integral value is written without a decimal point.
+                    match value.is_finite() && value.fract() == 0.0 {
+                        true => writer.write_all(b".0"),
+                        false => Ok(()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_write_json_for_float!(f32, f64);
+
+/// `None` is written as `null`.
+impl<T: WriteJson> WriteJson for Option<T> {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        match self {
+            Some(value) => value.write_json(writer),
+            None => writer.write_all(b"null"),
+        }
+    }
+}
+
+macro_rules! impl_write_json_for_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: WriteJson + ?Sized> WriteJson for $pointer<T> {
+                fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+                    (**self).write_json(writer)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_json_for_pointer!(Box, Rc, Arc);
+
+impl<T: WriteJson + ?Sized> WriteJson for &T {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        (**self).write_json(writer)
+    }
+}
+
+impl<T: WriteJson + ToOwned + ?Sized> WriteJson for Cow<'_, T> {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        self.as_ref().write_json(writer)
+    }
+}
+
+fn write_array<'a, T: WriteJson + 'a>(writer: &mut dyn Write, elements: impl IntoIterator<Item = &'a T>) -> io::Result<()> {
+    writer.write_all(b"[")?;
+
+    for (index, element) in elements.into_iter().enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+
+        element.write_json(writer)?;
+    }
+
+    writer.write_all(b"]")
+}
+
+fn write_object<'a, V: WriteJson + 'a>(writer: &mut dyn Write, properties: impl IntoIterator<Item = (&'a String, &'a V)>) -> io::Result<()> {
+    writer.write_all(b"{")?;
+
+    for (index, (key, value)) in properties.into_iter().enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+
+        key.write_json(writer)?;
+        writer.write_all(b":")?;
+        value.write_json(writer)?;
+    }
+
+    writer.write_all(b"}")
+}
+
+impl<T: WriteJson, const COUNT: usize> WriteJson for [T; COUNT] {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        write_array(writer, self)
+    }
+}
+
+/// Writes every element of a collection into an array.
+macro_rules! impl_write_json_for_collection {
+    ($($collection:ty),*) => {
+        $(
+            impl<T: WriteJson> WriteJson for $collection {
+                fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+                    write_array(writer, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_json_for_collection!([T], Vec<T>, VecDeque<T>, LinkedList<T>, HashSet<T>, BTreeSet<T>, BinaryHeap<T>);
+
+impl<V: WriteJson> WriteJson for HashMap<String, V> {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        write_object(writer, self)
+    }
+}
+
+impl<V: WriteJson> WriteJson for BTreeMap<String, V> {
+    fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        write_object(writer, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::WriteJson;
+    use crate::{JsonNode, ToJsonNode};
+
+    fn to_json(value: &dyn WriteJson) -> String {
+        let mut buffer = Vec::new();
+        value.write_json(&mut buffer).unwrap();
+
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn write_json_matches_to_json_string() {
+        let numbers = vec![Some(1.5), None, Some(-2.0), Some(1e21)];
+        let map = BTreeMap::from([("a\"b".to_owned(), vec![1u8, 2]), ("é\n".to_owned(), Vec::new())]);
+
+        assert_eq!(to_json(&numbers), numbers.iter().map(|number| number.to_json_node()).collect::<JsonNode>().to_json_string());
+        assert_eq!(to_json(&map), r#"{"a\"b":[1,2],"é\n":[]}"#);
+        assert_eq!(to_json(&"tab\there"), JsonNode::String("tab\there".to_owned()).to_json_string());
+        assert_eq!(to_json(&1.0f32), "1.0");
+        assert_eq!(to_json(&-3i8), "-3");
+        assert_eq!(to_json(&[u64::MAX]), "[18446744073709551615]");
+        assert_eq!(to_json(&JsonNode::parse(r#"{"a": [1, null]}"#).unwrap()), r#"{"a":[1,null]}"#);
+    }
+}